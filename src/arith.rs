@@ -0,0 +1,159 @@
+use prusti_contracts::*;
+
+// Integer helpers with exact specs. `Prusti.toml` turns off overflow checking for the
+// whole crate, so the functions below state their overflow arguments explicitly:
+// the `prusti_assert!`s compute the critical intermediate values in `i64`, where they
+// can't overflow, and check that they fit into the narrower type.
+
+// The midpoint of two `i32`s, without ever leaving the `i32` range.
+// `(a + b) / 2` overflows when both are large, `a + (b - a) / 2` when their signs differ
+// (the classic binary search bug). Choosing by sign avoids both.
+#[ensures(a <= b ==> a <= result && result <= b)]
+#[ensures(b < a ==> b <= result && result <= a)]
+// At most half a step away from the exact midpoint
+#[ensures(-1 <= 2 * result as i64 - (a as i64 + b as i64) && 2 * result as i64 - (a as i64 + b as i64) <= 1)]
+pub fn midpoint(a: i32, b: i32) -> i32 {
+    if (a >= 0) == (b >= 0) {
+        prusti_assert!(i32::MIN as i64 <= b as i64 - a as i64 && b as i64 - a as i64 <= i32::MAX as i64);
+        a + (b - a) / 2
+    } else {
+        prusti_assert!(i32::MIN as i64 <= a as i64 + b as i64 && a as i64 + b as i64 <= i32::MAX as i64);
+        (a + b) / 2
+    }
+}
+
+// Unlike `abs(a - b)` this can't overflow: the difference of two `i32`s always fits into a `u32`
+#[ensures(a >= b ==> result as i64 == a as i64 - b as i64)]
+#[ensures(a < b ==> result as i64 == b as i64 - a as i64)]
+pub fn abs_diff(a: i32, b: i32) -> u32 {
+    prusti_assert!(b as i64 - a as i64 <= u32::MAX as i64 && a as i64 - b as i64 <= u32::MAX as i64);
+    if a >= b {
+        (a as i64 - b as i64) as u32
+    } else {
+        (b as i64 - a as i64) as u32
+    }
+}
+
+#[pure]
+#[ensures(result == if x > 0 { 1 } else if x < 0 { -1 } else { 0 })]
+// `signum(x) * x` is `|x|`, so it is never negative (computed in `i64` for `i32::MIN`)
+#[ensures(result as i64 * x as i64 >= 0)]
+pub fn signum(x: i32) -> i32 {
+    if x > 0 {
+        1
+    } else if x < 0 {
+        -1
+    } else {
+        0
+    }
+}
+
+// Quotient and remainder with `0 <= r < |d|`, also for negative `a` and `d`
+// (Rust's `/` and `%` round towards zero, so their remainder takes the sign of `a`)
+#[requires(d != 0)]
+#[requires(!(a == i64::MIN && d == -1))]
+#[ensures(a == result.0 * d + result.1)]
+#[ensures(0 <= result.1)]
+#[ensures(d > 0 ==> result.1 < d)]
+#[ensures(d < 0 ==> result.1 + d < 0)]
+pub fn div_rem_euclid(a: i64, d: i64) -> (i64, i64) {
+    (a.div_euclid(d), a.rem_euclid(d))
+}
+
+// `a / b` panics for `b == 0` and overflows for `i32::MIN / -1`
+#[ensures(b == 0 ==> result.is_none())]
+#[ensures((a == i32::MIN && b == -1) ==> result.is_none())]
+#[ensures((b != 0 && !(a == i32::MIN && b == -1)) ==> result === Some(a / b))]
+pub fn checked_div(a: i32, b: i32) -> Option<i32> {
+    if b == 0 || (a == i32::MIN && b == -1) {
+        None
+    } else {
+        Some(a / b)
+    }
+}
+
+// The `unwrap` can't panic: a positive divisor rules out both cases that give `None`
+#[requires(count > 0)]
+#[ensures(result == total / count)]
+pub fn per_item(total: i32, count: i32) -> i32 {
+    checked_div(total, count).unwrap()
+}
+
+// Shift-and-add ("Russian peasant") multiplication: halve `y`, double `x`, and add `x` to the
+// accumulator whenever `y` is odd. `acc + x * y` stays equal to the product throughout; the
+// invariant computes it in `u128`, where it can't overflow. Every intermediate value is at most
+// the product, except that `x` would double once more after the last step, which is skipped.
+// The assertions check both bounds where the additions happen.
+#[requires(a as u128 * b as u128 <= u64::MAX as u128)]
+#[ensures(result as u128 == a as u128 * b as u128)]
+pub fn mul_by_addition(a: u64, b: u64) -> u64 {
+    let mut x = a;
+    let mut y = b;
+    let mut acc = 0;
+    while y > 0 {
+        body_invariant!(y > 0);
+        body_invariant!(acc as u128 + x as u128 * y as u128 == a as u128 * b as u128);
+
+        if y % 2 == 1 {
+            prusti_assert!(acc as u128 + x as u128 <= u64::MAX as u128);
+            acc += x;
+        }
+        y /= 2;
+        if y > 0 {
+            prusti_assert!(x as u128 * 2 <= u64::MAX as u128);
+            x *= 2;
+        }
+    }
+    acc
+}
+
+#[cfg(prusti)]
+mod prusti_tests {
+    use super::*;
+
+    fn _test_midpoint() {
+        let m = midpoint(i32::MAX, i32::MAX - 2);
+        prusti_assert!(m == i32::MAX - 1);
+
+        let n = midpoint(i32::MIN, i32::MAX);
+        prusti_assert!(n == 0 || n == -1);
+    }
+
+    fn _test_abs_diff() {
+        let d = abs_diff(i32::MIN, i32::MAX);
+        prusti_assert!(d == u32::MAX);
+        let e = abs_diff(3, 10);
+        prusti_assert!(e == 7);
+
+        prusti_assert!(signum(-5) == -1 && signum(0) == 0 && signum(i32::MIN) == -1);
+    }
+
+    fn _test_div_rem_euclid() {
+        let (q, r) = div_rem_euclid(-7, 2);
+        prusti_assert!(q == -4 && r == 1);
+        let (q2, r2) = div_rem_euclid(7, -2);
+        prusti_assert!(q2 == -3 && r2 == 1);
+        // div_rem_euclid(i64::MIN, -1); // FAILS: the quotient doesn't fit into an `i64`
+    }
+
+    fn _test_checked_div() {
+        let q = checked_div(7, 2);
+        prusti_assert!(q === Some(3));
+        let by_zero = checked_div(7, 0);
+        prusti_assert!(by_zero.is_none());
+        let overflow = checked_div(i32::MIN, -1);
+        prusti_assert!(overflow.is_none());
+
+        let x = per_item(10, 5);
+        prusti_assert!(x == 2);
+        // per_item(10, 0); // FAILS: precondition
+    }
+
+    fn _test_mul_by_addition() {
+        let p = mul_by_addition(6, 7);
+        prusti_assert!(p == 42);
+        let zero = mul_by_addition(u64::MAX, 0);
+        prusti_assert!(zero == 0);
+        // mul_by_addition(u64::MAX, 2); // FAILS: the product doesn't fit into a `u64`
+    }
+}
\ No newline at end of file
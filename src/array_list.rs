@@ -0,0 +1,109 @@
+use prusti_contracts::*;
+
+// A list with the same contract as `List`, but stored inline in an array of capacity `N`.
+// Like `BoundedStack`, the last array slot in use is the head of the list, so `push`
+// and `pop` don't have to move any elements.
+//
+// Instead of requiring `!is_full()`, `push` reports a full list with an error.
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Full;
+
+#[invariant(self.len <= N)]
+pub struct ArrayList<T: Copy + Default, const N: usize> {
+    elems: [T; N],
+    len: usize,
+}
+
+impl<T: Copy + Default, const N: usize> ArrayList<T, N> {
+    #[ensures(result.len() == 0)]
+    pub fn new() -> Self {
+        ArrayList { elems: [T::default(); N], len: 0 }
+    }
+
+    #[pure]
+    #[ensures(result <= N)]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[pure]
+    #[ensures(result == (self.len() == 0))]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[pure]
+    #[ensures(result == (self.len() == N))]
+    pub fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    #[pure]
+    #[requires(index < self.len())]
+    pub fn lookup(&self, index: usize) -> &T {
+        &self.elems[self.len - 1 - index]
+    }
+
+    #[ensures(match result {
+        Ok(()) => !old(self.is_full())
+            && self.len() == old(self.len()) + 1
+            && *self.lookup(0) === elem
+            && forall(|i: usize| i < old(self.len()) ==> old(self.lookup(i)) === self.lookup(i + 1)),
+        Err(Full) => old(self.is_full())
+            && self.len() == old(self.len())
+            && forall(|i: usize| i < self.len() ==> old(self.lookup(i)) === self.lookup(i)),
+    })]
+    pub fn push(&mut self, elem: T) -> Result<(), Full> {
+        if self.len == N {
+            return Err(Full);
+        }
+        self.elems[self.len] = elem;
+        self.len += 1;
+        Ok(())
+    }
+
+    #[ensures(old(self.is_empty()) ==> result.is_none() && self.is_empty())]
+    #[ensures(!old(self.is_empty()) ==>
+        self.len() == old(self.len()) - 1
+        && result === Some(old(*self.lookup(0)))
+        && forall(|i: usize| i < self.len() ==> old(self.lookup(i + 1)) === self.lookup(i)))]
+    pub fn try_pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            None
+        } else {
+            self.len -= 1;
+            Some(self.elems[self.len])
+        }
+    }
+
+    #[requires(!self.is_empty())]
+    #[ensures(self.len() == old(self.len()) - 1)]
+    #[ensures(result === old(*self.lookup(0)))]
+    #[ensures(forall(|i: usize| i < self.len() ==> old(self.lookup(i + 1)) === self.lookup(i)))]
+    pub fn pop(&mut self) -> T {
+        self.try_pop().unwrap()
+    }
+}
+
+#[cfg(prusti)]
+mod prusti_tests {
+    use super::*;
+
+    fn _test_array_list() {
+        let mut list: ArrayList<i32, 2> = ArrayList::new();
+        let first = list.push(5);
+        prusti_assert!(first === Ok(()));
+        let second = list.push(10);
+        prusti_assert!(second === Ok(()));
+        prusti_assert!(list.len() == 2 && *list.lookup(0) == 10 && *list.lookup(1) == 5);
+
+        // The list is full now, and stays unchanged
+        let third = list.push(15);
+        prusti_assert!(third === Err(Full));
+        prusti_assert!(list.len() == 2 && *list.lookup(0) == 10);
+
+        let x = list.pop();
+        prusti_assert!(x == 10 && list.len() == 1);
+    }
+}
\ No newline at end of file
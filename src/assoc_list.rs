@@ -0,0 +1,129 @@
+use prusti_contracts::*;
+
+use crate::list_generic_with_peek::List;
+
+// A map from keys to values, stored as a list of `(key, value)` pairs.
+// Every key occurs at most once, so a key maps to exactly one value if it is present.
+//
+// Clients reason about it with the predicates `has_key` and `maps_to`, which
+// don't depend on where in the list an entry is.
+#[invariant(forall(|i: usize, j: usize| (i < j && j < self.entries.len()) ==>
+    self.entries.lookup(i).0 != self.entries.lookup(j).0))]
+pub struct AssocList<K: Copy + PartialEq, V: Copy> {
+    entries: List<(K, V)>,
+}
+
+impl<K: Copy + PartialEq, V: Copy> AssocList<K, V> {
+    #[ensures(result.len() == 0)]
+    pub fn new() -> Self {
+        AssocList { entries: List::new() }
+    }
+
+    #[pure]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[pure]
+    #[requires(index < self.len())]
+    pub fn key_at(&self, index: usize) -> K {
+        self.entries.lookup(index).0
+    }
+
+    #[pure]
+    #[requires(index < self.len())]
+    pub fn value_at(&self, index: usize) -> V {
+        self.entries.lookup(index).1
+    }
+
+    predicate! {
+        pub fn has_key(&self, key: &K) -> bool {
+            exists(|i: usize| i < self.len() && self.key_at(i) == *key)
+        }
+    }
+
+    predicate! {
+        pub fn maps_to(&self, key: &K, value: &V) -> bool {
+            exists(|i: usize| i < self.len() && self.key_at(i) == *key && self.value_at(i) === *value)
+        }
+    }
+
+    #[ensures(match result {
+        Some(index) => index < self.len() && self.key_at(index) == *key,
+        None => !self.has_key(key),
+    })]
+    fn position(&self, key: &K) -> Option<usize> {
+        let mut i = 0;
+        while i < self.len() {
+            body_invariant!(i < self.len());
+            body_invariant!(forall(|j: usize| j < i ==> self.key_at(j) != *key));
+
+            if self.key_at(i) == *key {
+                return Some(i);
+            }
+            i += 1;
+        }
+        None
+    }
+
+    #[ensures(match result {
+        Some(value) => self.maps_to(key, value),
+        None => !self.has_key(key),
+    })]
+    pub fn get(&self, key: &K) -> Option<&V> {
+        match self.position(key) {
+            Some(index) => Some(&self.entries.lookup(index).1),
+            None => None,
+        }
+    }
+
+    // Returns the value that was stored for `key`, if any
+    #[ensures(!self.has_key(key))]
+    #[ensures(match result {
+        Some(value) => old(self.maps_to(key, &value)),
+        None => !old(self.has_key(key)),
+    })]
+    #[ensures(forall(|k: K| k != *key ==> self.has_key(&k) == old(self.has_key(&k))))]
+    #[ensures(forall(|k: K, v: V| k != *key ==> self.maps_to(&k, &v) == old(self.maps_to(&k, &v))))]
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        match self.position(key) {
+            Some(index) => {
+                let (_, value) = self.entries.remove_at(index);
+                Some(value)
+            }
+            None => None,
+        }
+    }
+
+    // Replaces the value if `key` is already present
+    #[ensures(self.maps_to(&key, &value))]
+    #[ensures(forall(|k: K| k != key ==> self.has_key(&k) == old(self.has_key(&k))))]
+    #[ensures(forall(|k: K, v: V| k != key ==> self.maps_to(&k, &v) == old(self.maps_to(&k, &v))))]
+    pub fn insert(&mut self, key: K, value: V) {
+        self.remove(&key);
+        self.entries.push((key, value));
+    }
+}
+
+#[cfg(prusti)]
+mod prusti_tests {
+    use super::*;
+
+    fn _test_assoc_list() {
+        let mut map = AssocList::new();
+        map.insert(1, 10);
+        map.insert(2, 20);
+        map.insert(1, 11);
+
+        // Keys are unique, so `1` can only map to the value inserted last
+        let one = map.get(&1);
+        prusti_assert!(one === Some(&11));
+        let two = map.get(&2);
+        prusti_assert!(two === Some(&20));
+
+        let removed = map.remove(&2);
+        prusti_assert!(removed === Some(20));
+        let gone = map.get(&2);
+        prusti_assert!(gone.is_none());
+    }
+}
\ No newline at end of file
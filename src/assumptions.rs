@@ -0,0 +1,90 @@
+use prusti_contracts::*;
+
+// `prusti_assume!` tells Prusti to take a fact for granted. That is only sound for facts
+// that really hold but can't be proven from the code, typically guarantees of the environment
+// (configuration, the OS, another service). A wrong assumption silently makes every
+// proof that depends on it worthless.
+//
+// `assume_checked!` pairs the assumption with a `debug_assert!` when the `runtime-checks`
+// feature is enabled, so test runs notice when the environment stops keeping its promise.
+// The condition has to be plain Rust for that (no `==>`, `forall`, ...).
+macro_rules! assume_checked {
+    ($cond:expr) => {
+        prusti_assume!($cond);
+        #[cfg(feature = "runtime-checks")]
+        debug_assert!($cond, "assumption violated: {}", stringify!($cond));
+    };
+}
+
+pub const MAX_CONNECTIONS: u32 = 1024;
+const DEFAULT_CONNECTIONS: u32 = 16;
+
+// Reading the environment is outside of what Prusti can reason about
+#[trusted]
+fn read_env_u32(name: &str, default: u32) -> u32 {
+    match std::env::var(name) {
+        Ok(value) => value.parse().unwrap_or(default),
+        Err(_) => default,
+    }
+}
+
+// The deployment validates `MAX_CONNECTIONS` before starting the program,
+// so the value is known to be in range
+#[ensures(1 <= result && result <= MAX_CONNECTIONS)]
+pub fn max_connections() -> u32 {
+    let value = read_env_u32("MAX_CONNECTIONS", DEFAULT_CONNECTIONS);
+    assume_checked!(1 <= value && value <= MAX_CONNECTIONS);
+    value
+}
+
+// When the fact can be checked cheaply, checking is better than assuming:
+// this version is verified without any assumption, at the cost of a runtime branch
+#[ensures(1 <= result && result <= MAX_CONNECTIONS)]
+pub fn max_connections_clamped() -> u32 {
+    let value = read_env_u32("MAX_CONNECTIONS", DEFAULT_CONNECTIONS);
+    if value < 1 {
+        1
+    } else if value > MAX_CONNECTIONS {
+        MAX_CONNECTIONS
+    } else {
+        value
+    }
+}
+
+pub struct ConnectionTable {
+    slots: [bool; MAX_CONNECTIONS as usize],
+    limit: u32,
+}
+
+impl ConnectionTable {
+    #[requires(1 <= limit && limit <= MAX_CONNECTIONS)]
+    #[ensures(result.limit() == limit)]
+    pub fn new(limit: u32) -> Self {
+        ConnectionTable { slots: [false; MAX_CONNECTIONS as usize], limit }
+    }
+
+    #[pure]
+    pub fn limit(&self) -> u32 {
+        self.limit
+    }
+
+    // Indexing is in bounds because of the precondition of `new`, which in turn
+    // relies on the assumption in `max_connections`
+    #[requires(self.limit() <= MAX_CONNECTIONS)]
+    #[requires(id < self.limit())]
+    pub fn open(&mut self, id: u32) {
+        self.slots[id as usize] = true;
+    }
+}
+
+#[cfg(prusti)]
+mod prusti_tests {
+    use super::*;
+
+    fn _test_config() {
+        let limit = max_connections();
+        let mut table = ConnectionTable::new(limit);
+        table.open(0);
+        // table.open(MAX_CONNECTIONS); // FAILS: out of range even for the largest limit
+    }
+}
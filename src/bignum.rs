@@ -0,0 +1,118 @@
+use prusti_contracts::*;
+
+// Unbounded natural numbers as base-10 digits, least significant digit first.
+// Their values quickly exceed every machine integer, so the spec functions use `Int`
+// and only exist for Prusti (see `int_sum`).
+
+predicate! {
+    pub fn is_decimal(digits: &[u8]) -> bool {
+        forall(|i: usize| i < digits.len() ==> digits[i] <= 9)
+    }
+}
+
+// Digits beyond the end are leading zeros
+#[pure]
+fn digit(digits: &[u8], i: usize) -> u8 {
+    if i < digits.len() {
+        digits[i]
+    } else {
+        0
+    }
+}
+
+#[cfg(prusti)]
+#[pure]
+#[terminates(Int::new_usize(k))]
+fn pow10(k: usize) -> Int {
+    if k == 0 {
+        Int::new(1)
+    } else {
+        Int::new(10) * pow10(k - 1)
+    }
+}
+
+// Value of the `k` least significant digits
+#[cfg(prusti)]
+#[pure]
+#[terminates(Int::new_usize(k))]
+fn value_upto(digits: &[u8], k: usize) -> Int {
+    if k == 0 {
+        Int::new(0)
+    } else {
+        value_upto(digits, k - 1) + Int::new(digit(digits, k - 1) as i64) * pow10(k - 1)
+    }
+}
+
+#[cfg(prusti)]
+#[pure]
+pub fn value_of(digits: &[u8]) -> Int {
+    value_upto(digits, digits.len())
+}
+
+// Lemma: the value of the first `k` digits only depends on these digits
+#[cfg(prusti)]
+#[pure]
+#[requires(forall(|j: usize| j < k ==> digit(a, j) == digit(b, j)))]
+#[ensures(value_upto(a, k) == value_upto(b, k))]
+#[terminates(Int::new_usize(k))]
+fn lemma_same_prefix(a: &[u8], b: &[u8], k: usize) -> bool {
+    k == 0 || lemma_same_prefix(a, b, k - 1)
+}
+
+// Lemma: leading zeros don't change the value
+#[cfg(prusti)]
+#[pure]
+#[requires(digits.len() <= k)]
+#[ensures(value_upto(digits, k) == value_of(digits))]
+#[terminates(Int::new_usize(k))]
+fn lemma_leading_zeros(digits: &[u8], k: usize) -> bool {
+    k == digits.len() || lemma_leading_zeros(digits, k - 1)
+}
+
+// Schoolbook addition. The invariant says that the digits written so far plus the carry
+// (which is worth `10^i` at position `i`) add up to the value of the digits of `a` and `b` read so far.
+#[requires(is_decimal(a) && is_decimal(b))]
+#[ensures(is_decimal(result.as_slice()))]
+#[ensures(value_of(result.as_slice()) == value_of(a) + value_of(b))]
+pub fn bignum_add(a: &[u8], b: &[u8]) -> Vec<u8> {
+    let n = if a.len() > b.len() { a.len() } else { b.len() };
+    let mut result = Vec::new();
+    let mut carry: u8 = 0;
+    let mut i = 0;
+    while i < n {
+        body_invariant!(i < n && result.len() == i && carry <= 1);
+        body_invariant!(is_decimal(result.as_slice()));
+        body_invariant!(value_upto(result.as_slice(), i) + Int::new(carry as i64) * pow10(i)
+            == value_upto(a, i) + value_upto(b, i));
+
+        let sum = digit(a, i) + digit(b, i) + carry;
+        #[cfg(prusti)]
+        let before = ghost!(snap(&result));
+        result.push(sum % 10);
+        prusti_assert!(lemma_same_prefix(before.as_slice(), result.as_slice(), i));
+        carry = sum / 10;
+        i += 1;
+    }
+
+    #[cfg(prusti)]
+    let before = ghost!(snap(&result));
+    if carry > 0 {
+        result.push(carry);
+        prusti_assert!(lemma_same_prefix(before.as_slice(), result.as_slice(), n));
+    }
+    prusti_assert!(lemma_leading_zeros(a, n) && lemma_leading_zeros(b, n));
+    result
+}
+
+#[cfg(prusti)]
+mod prusti_tests {
+    use super::*;
+
+    fn _test_bignum_add() {
+        // 99 + 1 == 100
+        let a = [9, 9];
+        let b = [1];
+        let sum = bignum_add(&a, &b);
+        prusti_assert!(value_of(sum.as_slice()) == Int::new(100));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,127 @@
+use prusti_contracts::*;
+
+// Binary digits are stored most significant digit first and without leading zeros,
+// so `0` is represented by the empty vector.
+
+#[pure]
+#[requires(k < 64)]
+#[cfg_attr(prusti, terminates(Int::new(k as i64)))]
+pub fn pow2(k: u32) -> u64 {
+    if k == 0 {
+        1
+    } else {
+        2 * pow2(k - 1)
+    }
+}
+
+predicate! {
+    // Every digit is either 0 or 1
+    pub fn is_binary(digits: &[u8]) -> bool {
+        forall(|i: usize| i < digits.len() ==> digits[i] <= 1)
+    }
+}
+
+// Value of the first `k` digits (Horner's scheme)
+#[pure]
+#[requires(k <= digits.len())]
+#[cfg_attr(prusti, terminates(Int::new_usize(k)))]
+pub fn value_upto(digits: &[u8], k: usize) -> u64 {
+    if k == 0 {
+        0
+    } else {
+        2 * value_upto(digits, k - 1) + digits[k - 1] as u64
+    }
+}
+
+#[pure]
+pub fn value_of(digits: &[u8]) -> u64 {
+    value_upto(digits, digits.len())
+}
+
+// Lemma: the value of a prefix only depends on the digits inside that prefix.
+// Prusti can't do the induction on its own, so the recursive call spells it out.
+#[pure]
+#[requires(k <= a.len() && k <= b.len())]
+#[requires(forall(|i: usize| i < k ==> a[i] == b[i]))]
+#[ensures(value_upto(a, k) == value_upto(b, k))]
+#[cfg_attr(prusti, terminates(Int::new_usize(k)))]
+#[allow(clippy::only_used_in_recursion)]
+fn lemma_prefix_value(a: &[u8], b: &[u8], k: usize) -> bool {
+    k == 0 || lemma_prefix_value(a, b, k - 1)
+}
+
+#[requires(digit <= 1)]
+#[requires(is_binary(digits.as_slice()))]
+#[ensures(digits.len() == old(digits.len()) + 1)]
+#[ensures(is_binary(digits.as_slice()))]
+// `push` leaves the existing digits untouched, so their value is unchanged
+#[ensures(lemma_prefix_value(old(snap(digits)).as_slice(), digits.as_slice(), old(digits.len())))]
+#[ensures(value_of(digits.as_slice()) == 2 * old(value_of(digits.as_slice())) + digit as u64)]
+fn push_digit(digits: &mut Vec<u8>, digit: u8) {
+    digits.push(digit);
+}
+
+#[ensures(is_binary(result.as_slice()))]
+#[ensures(result.len() <= 32)]
+#[ensures(value_of(result.as_slice()) == n as u64)]
+pub fn to_binary_digits(n: u32) -> Vec<u8> {
+    let mut digits = Vec::new();
+    let mut bit = 32;
+
+    while bit > 0 {
+        body_invariant!(bit <= 32);
+        body_invariant!(digits.len() <= 32 - bit);
+        body_invariant!(is_binary(digits.as_slice()));
+        // The digits so far are exactly `n >> bit`
+        body_invariant!(value_of(digits.as_slice()) == n as u64 / pow2(bit));
+
+        bit -= 1;
+        let digit = (n as u64 / pow2(bit) % 2) as u8;
+        // Skip leading zeros
+        if digit == 1 || !digits.is_empty() {
+            push_digit(&mut digits, digit);
+        }
+    }
+
+    digits
+}
+
+#[requires(is_binary(digits))]
+#[requires(digits.len() <= 32)]
+#[ensures(result as u64 == value_of(digits))]
+pub fn from_binary_digits(digits: &[u8]) -> u32 {
+    let mut acc: u32 = 0;
+    let mut i = 0;
+
+    while i < digits.len() {
+        body_invariant!(i < digits.len() && i < 32);
+        body_invariant!(acc as u64 == value_upto(digits, i));
+        // Needed to show that `2 * acc + 1` fits into a `u32`
+        body_invariant!(value_upto(digits, i) < pow2(i as u32));
+
+        acc = 2 * acc + digits[i] as u32;
+        i += 1;
+    }
+
+    acc
+}
+
+#[cfg(prusti)]
+mod prusti_tests {
+    use super::*;
+
+    fn _test_round_trip(n: u32) {
+        let digits = to_binary_digits(n);
+        let m = from_binary_digits(digits.as_slice());
+        prusti_assert!(m == n);
+    }
+
+    fn _test_digits() {
+        let digits = to_binary_digits(6);
+        prusti_assert!(value_of(digits.as_slice()) == 6);
+        prusti_assert!(digits.len() <= 32);
+
+        let zero = to_binary_digits(0);
+        prusti_assert!(value_of(zero.as_slice()) == 0);
+    }
+}
@@ -0,0 +1,221 @@
+use prusti_contracts::*;
+
+use crate::binary::pow2;
+
+// Bit-level utilities for `u32`. The specs are arithmetic (`pow2`, `%`, `/`), which is what
+// clients reason with; the `u32` methods they are built on have extern specs in `std_specs`.
+
+#[pure]
+#[ensures(result == exists(|k: u32| k < 32 && x as u64 == pow2(k)))]
+pub fn is_power_of_two(x: u32) -> bool {
+    x.is_power_of_two()
+}
+
+// The smallest power of two that is at least `x`
+#[requires(x <= 1 << 31)]
+#[ensures(is_power_of_two(result))]
+#[ensures(x <= result)]
+#[ensures(x <= 1 ==> result == 1)]
+#[ensures(x > 1 ==> result / 2 < x)]
+pub fn next_power_of_two(x: u32) -> u32 {
+    x.next_power_of_two()
+}
+
+// The smallest multiple of `align` that is at least `x`.
+// The usual `(x + align - 1) & !(align - 1)` relies on `align` being a power of two;
+// Prusti reasons much better about `%`, so this version works for any `align > 0`.
+#[requires(align > 0)]
+#[requires(x <= u32::MAX - (align - 1))]
+#[ensures(result % align == 0)]
+#[ensures(x <= result && result - x < align)]
+pub fn round_up_to(x: u32, align: u32) -> u32 {
+    let rem = x % align;
+    if rem == 0 {
+        x
+    } else {
+        x + (align - rem)
+    }
+}
+
+// The position of the highest set bit. Counts up instead of halving `n`: the loop condition
+// then is the spec itself, and `pow2(k + 1)` is only evaluated for `k + 1 < 64`.
+#[requires(n > 0)]
+#[ensures(result < 64)]
+#[ensures(pow2(result) <= n)]
+// `2^64` doesn't exist as a `u64`, every `n` is below it anyway
+#[ensures(result < 63 ==> n < pow2(result + 1))]
+pub fn ilog2(n: u64) -> u32 {
+    let mut k = 0;
+    while k < 63 && pow2(k + 1) <= n {
+        body_invariant!(k < 63 && pow2(k + 1) <= n);
+
+        k += 1;
+    }
+    k
+}
+
+// Lemma: `pow2` is monotone. Together with the bracketing of `ilog2` it pins the result
+// down: a larger exponent would make `pow2(result)` too big, a smaller one `pow2(result + 1)`
+// too small.
+#[pure]
+#[requires(a <= b && b < 64)]
+#[ensures(pow2(a) <= pow2(b))]
+#[cfg_attr(prusti, terminates(Int::new(b as i64)))]
+#[allow(clippy::only_used_in_recursion)]
+pub fn lemma_pow2_monotone(a: u32, b: u32) -> bool {
+    a == b || lemma_pow2_monotone(a, b - 1)
+}
+
+// A set of 32 flags. Bits are read and changed arithmetically (`/ pow2(bit) % 2`, `+ pow2(bit)`),
+// for the same reason as in `round_up_to`, and to match how `binary` talks about digits.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Flags(u32);
+
+impl Flags {
+    #[ensures(forall(|i: u32| i < 32 ==> !result.is_set(i)))]
+    pub fn empty() -> Self {
+        Flags(0)
+    }
+
+    #[pure]
+    pub fn bits(&self) -> u32 {
+        self.0
+    }
+
+    #[pure]
+    #[requires(bit < 32)]
+    pub fn is_set(&self, bit: u32) -> bool {
+        self.0 as u64 / pow2(bit) % 2 == 1
+    }
+
+    #[requires(bit < 32)]
+    #[ensures(self.is_set(bit))]
+    #[ensures(forall(|i: u32| (i < 32 && i != bit) ==> self.is_set(i) == old(self.is_set(i))))]
+    pub fn set(&mut self, bit: u32) {
+        if !self.is_set(bit) {
+            self.0 += pow2(bit) as u32;
+        }
+    }
+
+    #[requires(bit < 32)]
+    #[ensures(!self.is_set(bit))]
+    #[ensures(forall(|i: u32| (i < 32 && i != bit) ==> self.is_set(i) == old(self.is_set(i))))]
+    pub fn clear(&mut self, bit: u32) {
+        if self.is_set(bit) {
+            self.0 -= pow2(bit) as u32;
+        }
+    }
+}
+
+// Number of 1 digits, one binary digit at a time
+#[pure]
+#[ensures(result <= 32)]
+#[cfg_attr(prusti, terminates(Int::new(x as i64)))]
+pub fn popcount_spec(x: u32) -> u32 {
+    if x == 0 {
+        0
+    } else {
+        x % 2 + popcount_spec(x / 2)
+    }
+}
+
+// Always exactly 32 iterations. `rest < 2^(32 - i)` is what shows that all bits were
+// looked at in the end: after 32 halvings nothing is left.
+#[ensures(result == popcount_spec(x))]
+pub fn popcount(x: u32) -> u32 {
+    let mut count = 0;
+    let mut rest = x;
+    let mut i = 0;
+    while i < 32 {
+        body_invariant!(i < 32 && count <= i);
+        body_invariant!((rest as u64) < pow2(32 - i));
+        body_invariant!(count + popcount_spec(rest) == popcount_spec(x));
+
+        count += rest % 2;
+        rest /= 2;
+        i += 1;
+    }
+    count
+}
+
+// Little-endian byte order, computed arithmetically like the rest of this module
+// (same results as `u32::to_le_bytes`/`u32::from_le_bytes`)
+#[ensures(result[0] as u32 == x % 256)]
+#[ensures(result[1] as u32 == x / 256 % 256)]
+#[ensures(result[2] as u32 == x / 65536 % 256)]
+#[ensures(result[3] as u32 == x / 16777216)]
+pub fn u32_to_le_bytes(x: u32) -> [u8; 4] {
+    [(x % 256) as u8, (x / 256 % 256) as u8, (x / 65536 % 256) as u8, (x / 16777216) as u8]
+}
+
+#[ensures(result == bytes[0] as u32 + 256 * bytes[1] as u32
+    + 65536 * bytes[2] as u32 + 16777216 * bytes[3] as u32)]
+pub fn u32_from_le_bytes(bytes: [u8; 4]) -> u32 {
+    bytes[0] as u32 + 256 * bytes[1] as u32 + 65536 * bytes[2] as u32 + 16777216 * bytes[3] as u32
+}
+
+// Round trips in both directions
+#[ensures(result == x)]
+pub fn le_round_trip(x: u32) -> u32 {
+    u32_from_le_bytes(u32_to_le_bytes(x))
+}
+
+#[ensures(result === bytes)]
+pub fn le_round_trip_bytes(bytes: [u8; 4]) -> [u8; 4] {
+    u32_to_le_bytes(u32_from_le_bytes(bytes))
+}
+
+#[cfg(prusti)]
+mod prusti_tests {
+    use super::*;
+
+    fn _test_powers_of_two() {
+        prusti_assert!(pow2(3) == 8);
+        prusti_assert!(is_power_of_two(8));
+
+        let p = next_power_of_two(5);
+        prusti_assert!(5 <= p && p / 2 < 5);
+        // next_power_of_two(u32::MAX); // FAILS: the result wouldn't fit
+
+        let r = round_up_to(13, 8);
+        prusti_assert!(r == 16);
+    }
+
+    fn _test_flags() {
+        let mut flags = Flags::empty();
+        flags.set(3);
+        flags.set(5);
+        flags.clear(3);
+        prusti_assert!(!flags.is_set(3) && flags.is_set(5) && !flags.is_set(0));
+        // flags.set(32); // FAILS: there are only 32 bits
+    }
+
+    fn _test_popcount() {
+        let n = popcount(0b1011);
+        prusti_assert!(n == 3);
+        let all = popcount(u32::MAX);
+        prusti_assert!(all == popcount_spec(u32::MAX));
+    }
+
+    fn _test_le_bytes() {
+        let bytes = u32_to_le_bytes(0x12345678);
+        prusti_assert!(bytes[0] == 0x78 && bytes[3] == 0x12);
+        let x = u32_from_le_bytes(bytes);
+        prusti_assert!(x == 0x12345678);
+    }
+
+    fn _test_ilog2() {
+        let one = ilog2(1);
+        prusti_assert!(one == 0);
+        let eight = ilog2(8);
+        // Any other exponent contradicts `pow2(eight) <= 8 < pow2(eight + 1)`
+        prusti_assert!(eight < 4 || lemma_pow2_monotone(4, eight));
+        prusti_assert!(eight >= 3 || lemma_pow2_monotone(eight + 1, 3));
+        prusti_assert!(eight == 3);
+        let nine = ilog2(9);
+        prusti_assert!(nine < 4 || lemma_pow2_monotone(4, nine));
+        prusti_assert!(nine >= 3 || lemma_pow2_monotone(nine + 1, 3));
+        prusti_assert!(nine == 3);
+        // ilog2(0); // FAILS: there is no set bit
+    }
+}
\ No newline at end of file
@@ -0,0 +1,149 @@
+use prusti_contracts::*;
+
+use crate::pure_ord::PureOrd;
+
+// Binary search tree over any `PureOrd` element type
+#[invariant(is_bst_within(&self.root, None, None))]
+pub struct Tree<T: PureOrd> {
+    root: TreeLink<T>,
+}
+
+type TreeLink<T> = Option<Box<TreeNode<T>>>;
+
+struct TreeNode<T> {
+    elem: T,
+    left: TreeLink<T>,
+    right: TreeLink<T>,
+}
+
+#[pure]
+// Structural recursion on the tree, like `link_len` in `list_generic_with_peek`
+#[cfg_attr(prusti, terminates(trusted))]
+fn size<T>(link: &TreeLink<T>) -> usize {
+    match link {
+        None => 0,
+        Some(node) => 1 + size(&node.left) + size(&node.right),
+    }
+}
+
+// `elem` lies between the (optional) lower and upper bound
+#[pure]
+fn within<T: PureOrd>(elem: &T, lo: Option<&T>, hi: Option<&T>) -> bool {
+    let above_lo = match lo {
+        Some(lo) => lo.leq(elem),
+        None => true,
+    };
+    let below_hi = match hi {
+        Some(hi) => elem.leq(hi),
+        None => true,
+    };
+    above_lo && below_hi
+}
+
+// Every node lies between the bounds, which get tighter on the way down:
+// the left subtree of a node is bounded above by it, the right one below.
+// Carrying the bounds avoids a quantifier over all elements of a subtree.
+#[pure]
+#[cfg_attr(prusti, terminates(trusted))]
+fn is_bst_within<T: PureOrd>(link: &TreeLink<T>, lo: Option<&T>, hi: Option<&T>) -> bool {
+    match link {
+        None => true,
+        Some(node) => {
+            within(&node.elem, lo, hi)
+                && is_bst_within(&node.left, lo, Some(&node.elem))
+                && is_bst_within(&node.right, Some(&node.elem), hi)
+        }
+    }
+}
+
+// Membership by looking at every node, without using the ordering
+#[pure]
+#[cfg_attr(prusti, terminates(trusted))]
+fn tree_has<T: PureOrd>(link: &TreeLink<T>, x: &T) -> bool {
+    match link {
+        None => false,
+        Some(node) => node.elem.equiv(x) || tree_has(&node.left, x) || tree_has(&node.right, x),
+    }
+}
+
+#[requires(is_bst_within(link, lo, hi))]
+#[requires(within(&elem, lo, hi))]
+#[ensures(is_bst_within(link, lo, hi))]
+#[ensures(size(link) == old(size(link)) + 1)]
+// `lo` and `hi` are only there for the specification
+#[allow(clippy::only_used_in_recursion)]
+fn insert_link<T: PureOrd>(link: &mut TreeLink<T>, elem: T, lo: Option<&T>, hi: Option<&T>) {
+    match link {
+        None => {
+            *link = Some(Box::new(TreeNode { elem, left: None, right: None }));
+        }
+        Some(node) => {
+            if elem.leq(&node.elem) {
+                insert_link(&mut node.left, elem, lo, Some(&node.elem));
+            } else {
+                // `elem` is not below the node, so the node is below `elem`
+                prusti_assert!(elem.total(&node.elem));
+                insert_link(&mut node.right, elem, Some(&node.elem), hi);
+            }
+        }
+    }
+}
+
+// Only walks down a single path. The spec states soundness; completeness would additionally
+// need a lemma that every element of a subtree respects its bounds.
+#[requires(is_bst_within(link, lo, hi))]
+#[ensures(result ==> tree_has(link, x))]
+#[allow(clippy::only_used_in_recursion)]
+fn contains_link<T: PureOrd>(link: &TreeLink<T>, x: &T, lo: Option<&T>, hi: Option<&T>) -> bool {
+    match link {
+        None => false,
+        Some(node) => {
+            if node.elem.equiv(x) {
+                true
+            } else if x.leq(&node.elem) {
+                contains_link(&node.left, x, lo, Some(&node.elem))
+            } else {
+                contains_link(&node.right, x, Some(&node.elem), hi)
+            }
+        }
+    }
+}
+
+impl<T: PureOrd> Tree<T> {
+    #[ensures(result.size() == 0)]
+    pub fn new() -> Self {
+        Tree { root: None }
+    }
+
+    #[pure]
+    pub fn size(&self) -> usize {
+        size(&self.root)
+    }
+
+    #[ensures(self.size() == old(self.size()) + 1)]
+    pub fn insert(&mut self, elem: T) {
+        insert_link(&mut self.root, elem, None, None);
+    }
+
+    #[ensures(result ==> tree_has(&self.root, x))]
+    pub fn contains(&self, x: &T) -> bool {
+        contains_link(&self.root, x, None, None)
+    }
+}
+
+#[cfg(prusti)]
+mod prusti_tests {
+    use super::*;
+
+    fn _test_tree() {
+        let mut tree = Tree::new();
+        tree.insert(4);
+        tree.insert(2);
+        tree.insert(6);
+        prusti_assert!(tree.size() == 3);
+
+        if tree.contains(&2) {
+            prusti_assert!(tree_has(&tree.root, &2));
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use prusti_contracts::*;
+
+use crate::list_generic_with_peek::List;
+
+// A builder whose `build` can't be called with an incomplete or contradictory configuration.
+//
+// Each setter's postcondition says exactly what it changed and that everything else stayed
+// the same, so after a chain of setters Prusti knows every field. `is_valid` collects the
+// requirements on them, and `build` requires it instead of checking at runtime.
+pub struct ListBuilder<T: Copy> {
+    cap: Option<usize>,
+    fill: usize,
+    value: Option<T>,
+}
+
+impl<T: Copy> ListBuilder<T> {
+    #[ensures(!result.has_cap() && result.fill() == 0)]
+    pub fn new() -> Self {
+        ListBuilder { cap: None, fill: 0, value: None }
+    }
+
+    #[pure]
+    pub fn has_cap(&self) -> bool {
+        self.cap.is_some()
+    }
+
+    #[pure]
+    #[requires(self.has_cap())]
+    pub fn cap(&self) -> usize {
+        match self.cap {
+            Some(cap) => cap,
+            None => unreachable!(),
+        }
+    }
+
+    // Number of copies of the fill value the list starts with
+    #[pure]
+    pub fn fill(&self) -> usize {
+        self.fill
+    }
+
+    // A capacity is mandatory, and the initial elements have to fit
+    #[pure]
+    pub fn is_valid(&self) -> bool {
+        match self.cap {
+            Some(cap) => cap > 0 && self.fill <= cap && (self.fill == 0 || self.value.is_some()),
+            None => false,
+        }
+    }
+
+    #[ensures(result.has_cap() && result.cap() == cap)]
+    #[ensures(result.fill() == self.fill())]
+    #[ensures(result.value === self.value)]
+    pub fn with_capacity(mut self, cap: usize) -> Self {
+        self.cap = Some(cap);
+        self
+    }
+
+    #[ensures(result.fill() == count)]
+    #[ensures(result.value === Some(value))]
+    #[ensures(result.cap === self.cap)]
+    pub fn filled_with(mut self, count: usize, value: T) -> Self {
+        self.fill = count;
+        self.value = Some(value);
+        self
+    }
+
+    // The list starts with `fill()` elements, which is at most the capacity
+    #[requires(self.is_valid())]
+    #[ensures(result.len() == self.fill())]
+    #[ensures(result.len() <= self.cap())]
+    #[ensures(forall(|i: usize| i < result.len() ==> Some(*result.lookup(i)) === self.value))]
+    pub fn build(self) -> List<T> {
+        let mut list = List::new();
+        if let Some(value) = self.value {
+            let mut i = 0;
+            while i < self.fill {
+                body_invariant!(i < self.fill && list.len() == i);
+                body_invariant!(forall(|j: usize| j < list.len() ==> *list.lookup(j) === value));
+
+                list.push(value);
+                i += 1;
+            }
+        }
+        list
+    }
+}
+
+#[cfg(prusti)]
+mod prusti_tests {
+    use super::*;
+
+    fn _test_builder() {
+        let list = ListBuilder::new().with_capacity(4).filled_with(2, 7).build();
+        prusti_assert!(list.len() == 2);
+        prusti_assert!(*list.lookup(0) == 7 && *list.lookup(1) == 7);
+
+        // The order of the setters doesn't matter
+        let other = ListBuilder::new().filled_with(1, 0).with_capacity(1).build();
+        prusti_assert!(other.len() == 1);
+
+        let empty: List<i32> = ListBuilder::new().with_capacity(1).build();
+        prusti_assert!(empty.is_empty());
+
+        // ListBuilder::new().filled_with(1, 0).build(); // FAILS: no capacity
+        // ListBuilder::new().with_capacity(1).filled_with(2, 0).build(); // FAILS: doesn't fit
+    }
+}
\ No newline at end of file
@@ -0,0 +1,107 @@
+use prusti_contracts::*;
+
+use crate::list_generic_with_peek::List;
+
+// A list that remembers the last element it looked up.
+//
+// The cache is only useful if it agrees with the list, and the type invariant says exactly
+// that. Every method that changes the list has to bring the cache along (or drop it), and
+// `get` may then return the cached value without looking at the list at all.
+#[invariant(match self.cache {
+    Some((index, value)) => index < self.list.len() && *self.list.lookup(index) === value,
+    None => true,
+})]
+pub struct CachedList<T: Copy> {
+    list: List<T>,
+    cache: Option<(usize, T)>,
+}
+
+impl<T: Copy> CachedList<T> {
+    #[ensures(result.len() == 0)]
+    pub fn new() -> Self {
+        CachedList { list: List::new(), cache: None }
+    }
+
+    #[pure]
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    #[pure]
+    #[ensures(result == (self.len() == 0))]
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    // What clients reason with: the element, wherever it is currently stored
+    #[pure]
+    #[requires(index < self.len())]
+    pub fn lookup(&self, index: usize) -> T {
+        *self.list.lookup(index)
+    }
+
+    // Only the cache changes, which clients can't see
+    #[requires(index < self.len())]
+    #[ensures(result === self.lookup(index))]
+    #[ensures(self.len() == old(self.len()))]
+    #[ensures(forall(|i: usize| i < self.len() ==> self.lookup(i) === old(self.lookup(i))))]
+    pub fn get(&mut self, index: usize) -> T {
+        if let Some((cached, value)) = self.cache {
+            if cached == index {
+                return value;
+            }
+        }
+        let value = *self.list.lookup(index);
+        self.cache = Some((index, value));
+        value
+    }
+
+    // Everything moves back by one, so does the cached index
+    #[ensures(self.len() == old(self.len()) + 1)]
+    #[ensures(self.lookup(0) === elem)]
+    #[ensures(forall(|i: usize| i < old(self.len()) ==> self.lookup(i + 1) === old(self.lookup(i))))]
+    pub fn push(&mut self, elem: T) {
+        if let Some((cached, value)) = self.cache {
+            self.cache = Some((cached + 1, value));
+        }
+        self.list.push(elem);
+    }
+
+    // A cached head is gone afterwards, any other cached index moves forward by one
+    #[requires(!self.is_empty())]
+    #[ensures(self.len() == old(self.len()) - 1)]
+    #[ensures(result === old(self.lookup(0)))]
+    #[ensures(forall(|i: usize| (1 <= i && i < old(self.len())) ==>
+        self.lookup(i - 1) === old(self.lookup(i))))]
+    pub fn pop(&mut self) -> T {
+        self.cache = match self.cache {
+            Some((cached, value)) if cached > 0 => Some((cached - 1, value)),
+            _ => None,
+        };
+        self.list.pop()
+    }
+}
+
+#[cfg(prusti)]
+mod prusti_tests {
+    use super::*;
+
+    fn _test_cached_list() {
+        let mut list = CachedList::new();
+        list.push(3);
+        list.push(2);
+        list.push(1);
+        let x = list.get(1);
+        prusti_assert!(x == 2);
+        // The second lookup is answered by the cache, with the same result
+        let y = list.get(1);
+        prusti_assert!(y == 2);
+
+        list.push(0);
+        let z = list.get(2);
+        prusti_assert!(z == 2);
+        let head = list.pop();
+        prusti_assert!(head == 0);
+        prusti_assert!(list.lookup(1) == 2);
+    }
+}
\ No newline at end of file
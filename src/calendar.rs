@@ -0,0 +1,129 @@
+use prusti_contracts::*;
+
+// Gregorian calendar: divisible by 4, except centuries, except every 400 years
+#[pure]
+pub fn is_leap_year(year: u32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+#[pure]
+#[requires(1 <= month && month <= 12)]
+#[ensures(28 <= result && result <= 31)]
+#[ensures(month == 2 && is_leap_year(year) ==> result == 29)]
+#[ensures(month == 2 && !is_leap_year(year) ==> result == 28)]
+#[ensures((month == 4 || month == 6 || month == 9 || month == 11) ==> result == 30)]
+pub fn days_in_month(year: u32, month: u32) -> u32 {
+    match month {
+        2 => {
+            if is_leap_year(year) {
+                29
+            } else {
+                28
+            }
+        }
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+predicate! {
+    pub fn is_valid_date(year: u32, month: u32, day: u32) -> bool {
+        1 <= month && month <= 12
+        && 1 <= day && day <= days_in_month(year, month)
+    }
+}
+
+// The type invariant is checked whenever a `Date` is constructed or handed back to a caller,
+// so every `Date` in the program is a real calendar day.
+#[invariant(is_valid_date(self.year, self.month, self.day))]
+pub struct Date {
+    year: u32,
+    month: u32,
+    day: u32,
+}
+
+impl Date {
+    #[ensures(is_valid_date(year, month, day) ==> result.is_some())]
+    #[ensures(!is_valid_date(year, month, day) ==> result.is_none())]
+    pub fn new(year: u32, month: u32, day: u32) -> Option<Date> {
+        if 1 <= month && month <= 12 && 1 <= day && day <= days_in_month(year, month) {
+            Some(Date { year, month, day })
+        } else {
+            None
+        }
+    }
+
+    #[pure]
+    pub fn year(&self) -> u32 {
+        self.year
+    }
+
+    #[pure]
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    #[pure]
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+
+    // Three cases
+    // 1. Not the last day of the month: the day advances.
+    // 2. Last day of a month other than December: the first day of the next month.
+    // 3. December 31st: January 1st of the next year.
+    #[requires(self.year() < u32::MAX)]
+    #[ensures(is_valid_date(result.year(), result.month(), result.day()))]
+    #[ensures(self.day() < days_in_month(self.year(), self.month()) ==>
+        result.year() == self.year()
+        && result.month() == self.month()
+        && result.day() == self.day() + 1
+    )]
+    #[ensures(self.day() == days_in_month(self.year(), self.month()) && self.month() < 12 ==>
+        result.year() == self.year()
+        && result.month() == self.month() + 1
+        && result.day() == 1
+    )]
+    #[ensures(self.day() == days_in_month(self.year(), self.month()) && self.month() == 12 ==>
+        result.year() == self.year() + 1
+        && result.month() == 1
+        && result.day() == 1
+    )]
+    pub fn next_day(&self) -> Date {
+        if self.day < days_in_month(self.year, self.month) {
+            Date { year: self.year, month: self.month, day: self.day + 1 }
+        } else if self.month < 12 {
+            Date { year: self.year, month: self.month + 1, day: 1 }
+        } else {
+            Date { year: self.year + 1, month: 1, day: 1 }
+        }
+    }
+}
+
+#[cfg(prusti)]
+mod prusti_tests {
+    use super::*;
+
+    fn _test_leap_years() {
+        prusti_assert!(is_leap_year(2024));
+        prusti_assert!(!is_leap_year(1900));
+        prusti_assert!(is_leap_year(2000));
+        prusti_assert!(days_in_month(2023, 2) == 28);
+        prusti_assert!(days_in_month(2024, 2) == 29);
+    }
+
+    fn _test_next_day() {
+        let date = Date::new(2024, 2, 28);
+        prusti_assert!(date.is_some());
+
+        let date = date.unwrap();
+        let next = date.next_day();
+        prusti_assert!(next.month() == 2 && next.day() == 29);
+
+        let next = next.next_day();
+        prusti_assert!(next.month() == 3 && next.day() == 1);
+
+        prusti_assert!(Date::new(2023, 2, 29).is_none());
+        prusti_assert!(Date::new(2023, 13, 1).is_none());
+    }
+}
@@ -0,0 +1,113 @@
+use prusti_contracts::*;
+
+use crate::list_generic_with_peek::List;
+use crate::persistent::PList;
+
+// Checkpoints of a `List`, to undo changes. A checkpoint copies the elements once into a
+// persistent list. After that, keeping it around and sharing it doesn't copy anything, so
+// many checkpoints of related states are cheap. A `List` owns its nodes, though: `restore`
+// can't reuse the checkpoint's nodes and copies every element back into the list.
+// Both directions walk the links instead of calling `lookup` for every index, so they take
+// time linear in the length of the list.
+
+pub struct Snapshot<T> {
+    saved: PList<T>,
+}
+
+impl<T> Snapshot<T> {
+    #[pure]
+    pub fn len(&self) -> usize {
+        self.saved.len()
+    }
+
+    #[pure]
+    #[requires(index < self.len())]
+    pub fn lookup(&self, index: usize) -> &T {
+        self.saved.lookup(index)
+    }
+
+    // Constant time: the copy shares all nodes
+    #[ensures(result.len() == self.len())]
+    #[ensures(forall(|i: usize| i < self.len() ==> result.lookup(i) === self.lookup(i)))]
+    pub fn share(&self) -> Self {
+        Snapshot { saved: self.saved.share() }
+    }
+}
+
+impl<T: Copy> List<T> {
+    // `reversed` puts the last element in front, so popping hands out the elements from the
+    // back, and each one can be put in front of the ones already saved
+    #[ensures(result.len() == self.len())]
+    #[ensures(forall(|i: usize| i < self.len() ==> result.lookup(i) === self.lookup(i)))]
+    pub fn checkpoint(&self) -> Snapshot<T> {
+        let mut rest = self.reversed();
+        let mut saved = PList::new();
+        while !rest.is_empty() {
+            body_invariant!(rest.len() + saved.len() == self.len());
+            body_invariant!(forall(|j: usize| j < rest.len() ==>
+                rest.lookup(j) === self.lookup(rest.len() - 1 - j)));
+            body_invariant!(forall(|j: usize| j < saved.len() ==>
+                saved.lookup(j) === self.lookup(rest.len() + j)));
+
+            saved = saved.cons(rest.pop());
+        }
+        Snapshot { saved }
+    }
+
+    // Whatever happened to the list since, it holds exactly the checkpointed elements again.
+    // The snapshot itself stays as it is and can be restored again.
+    // The first loop walks the snapshot with `tail` and collects its elements in reverse, the
+    // second one pops them off again, in front of each other.
+    #[ensures(self.len() == snapshot.len())]
+    #[ensures(forall(|i: usize| i < self.len() ==> self.lookup(i) === snapshot.lookup(i)))]
+    pub fn restore(&mut self, snapshot: &Snapshot<T>) {
+        let mut rest = snapshot.saved.share();
+        let mut reversed = List::new();
+        while !rest.is_empty() {
+            body_invariant!(reversed.len() + rest.len() == snapshot.len());
+            body_invariant!(forall(|j: usize| j < rest.len() ==>
+                rest.lookup(j) === snapshot.lookup(reversed.len() + j)));
+            body_invariant!(forall(|j: usize| j < reversed.len() ==>
+                reversed.lookup(j) === snapshot.lookup(reversed.len() - 1 - j)));
+
+            reversed.push(*rest.lookup(0));
+            rest = rest.tail();
+        }
+
+        let mut list = List::new();
+        while !reversed.is_empty() {
+            body_invariant!(reversed.len() + list.len() == snapshot.len());
+            body_invariant!(forall(|j: usize| j < reversed.len() ==>
+                reversed.lookup(j) === snapshot.lookup(reversed.len() - 1 - j)));
+            body_invariant!(forall(|j: usize| j < list.len() ==>
+                list.lookup(j) === snapshot.lookup(reversed.len() + j)));
+
+            list.push(reversed.pop());
+        }
+        *self = list;
+    }
+}
+
+#[cfg(prusti)]
+mod prusti_tests {
+    use super::*;
+
+    fn _test_checkpoint() {
+        let mut list = List::new();
+        list.push(2);
+        list.push(1);
+        let saved = list.checkpoint();
+
+        list.pop();
+        list.push(7);
+        list.push(8);
+        prusti_assert!(list.len() == 3);
+
+        list.restore(&saved);
+        prusti_assert!(list.len() == 2 && *list.lookup(0) == 1 && *list.lookup(1) == 2);
+        // Restoring twice gives the same result
+        list.push(9);
+        list.restore(&saved);
+        prusti_assert!(list.len() == 2 && *list.lookup(0) == 1);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,70 @@
+use prusti_contracts::*;
+
+// Higher-order functions can't know what the closure they get does.
+// Instead, the caller promises a specification for it with `|=` ("spec entailment"):
+//     f |= |a: i32| [requires(P(a)), ensures(Q(a, cl_result))]
+// means: whenever `P(a)` holds, `f(a)` may be called and its result satisfies `Q`.
+
+#[requires(x >= 0)]
+#[requires(f |= |a: i32| [requires(a >= 0), ensures(cl_result >= a)])]
+#[ensures(result >= x)]
+pub fn apply<F: Fn(i32) -> i32>(f: F, x: i32) -> i32 {
+    f(x)
+}
+
+// The second call is only allowed because the first result satisfies the precondition again
+#[requires(x >= 0)]
+#[requires(f |= |a: i32| [requires(a >= 0), ensures(cl_result >= a)])]
+#[ensures(result >= x)]
+pub fn call_twice<F: Fn(i32) -> i32>(f: F, x: i32) -> i32 {
+    f(f(x))
+}
+
+// `g(f(x))`: the postcondition of `f` has to establish the precondition of `g`
+#[requires(x >= 0)]
+#[requires(f |= |a: i32| [requires(a >= 0), ensures(cl_result > a)])]
+#[requires(g |= |b: i32| [requires(b > 0), ensures(cl_result == 2 * b)])]
+#[ensures(result > 2 * x)]
+pub fn compose<F: Fn(i32) -> i32, G: Fn(i32) -> i32>(f: F, g: G, x: i32) -> i32 {
+    g(f(x))
+}
+
+#[cfg(prusti)]
+mod prusti_tests {
+    use super::*;
+
+    fn _test_apply() {
+        let inc = closure!(
+            requires(a >= 0),
+            ensures(result == a + 1),
+            |a: i32| -> i32 { a + 1 }
+        );
+        let x = apply(inc, 5);
+        prusti_assert!(x >= 5);
+    }
+
+    fn _test_call_twice() {
+        let add_three = closure!(
+            requires(a >= 0),
+            ensures(result == a + 3),
+            |a: i32| -> i32 { a + 3 }
+        );
+        let x = call_twice(add_three, 1);
+        prusti_assert!(x >= 1);
+    }
+
+    fn _test_compose() {
+        let inc = closure!(
+            requires(a >= 0),
+            ensures(result == a + 1),
+            |a: i32| -> i32 { a + 1 }
+        );
+        let double = closure!(
+            requires(b > 0),
+            ensures(result == 2 * b),
+            |b: i32| -> i32 { 2 * b }
+        );
+        let x = compose(inc, double, 3);
+        prusti_assert!(x > 6);
+    }
+}
@@ -0,0 +1,111 @@
+use prusti_contracts::*;
+
+use crate::list_generic_with_peek::List;
+
+// A container with stack-like access: index 0 is always the element that was pushed last.
+// Clients can be verified against these contracts alone, without knowing the implementation.
+pub trait Container<T> {
+    #[pure]
+    fn len(&self) -> usize;
+
+    #[pure]
+    #[requires(index < self.len())]
+    fn lookup(&self, index: usize) -> &T;
+
+    #[ensures(self.len() == old(self.len()) + 1)]
+    #[ensures(snap(self.lookup(0)) === elem)]
+    #[ensures(forall(|i: usize| (i < old(self.len())) ==>
+        old(self.lookup(i)) === self.lookup(i + 1)))]
+    fn push(&mut self, elem: T);
+
+    #[requires(self.len() > 0)]
+    #[ensures(self.len() == old(self.len()) - 1)]
+    #[ensures(result === old(snap(self.lookup(0))))]
+    #[ensures(forall(|i: usize| (1 <= i && i < old(self.len())) ==>
+        old(self.lookup(i)) === self.lookup(i - 1)))]
+    fn pop(&mut self) -> T;
+}
+
+// The boxed linked list already has exactly these contracts on its inherent methods
+#[refine_trait_spec]
+impl<T> Container<T> for List<T> {
+    #[pure]
+    fn len(&self) -> usize {
+        List::len(self)
+    }
+
+    #[pure]
+    fn lookup(&self, index: usize) -> &T {
+        List::lookup(self, index)
+    }
+
+    fn push(&mut self, elem: T) {
+        List::push(self, elem)
+    }
+
+    fn pop(&mut self) -> T {
+        List::pop(self)
+    }
+}
+
+// A list backed by a `Vec`. The elements are stored in reverse order,
+// so pushing and popping at index 0 are cheap `Vec::push`/`Vec::pop` calls.
+pub struct VecList<T> {
+    elems: Vec<T>,
+}
+
+impl<T> VecList<T> {
+    #[ensures(result.len() == 0)]
+    pub fn new() -> Self {
+        VecList { elems: Vec::new() }
+    }
+}
+
+#[refine_trait_spec]
+impl<T> Container<T> for VecList<T> {
+    #[pure]
+    fn len(&self) -> usize {
+        self.elems.len()
+    }
+
+    #[pure]
+    fn lookup(&self, index: usize) -> &T {
+        &self.elems[self.elems.len() - 1 - index]
+    }
+
+    fn push(&mut self, elem: T) {
+        self.elems.push(elem);
+    }
+
+    fn pop(&mut self) -> T {
+        self.elems.pop().unwrap()
+    }
+}
+
+#[cfg(prusti)]
+mod prusti_tests {
+    use super::*;
+
+    fn _test_vec_list() {
+        let mut list = VecList::new();
+        list.push(5);
+        list.push(10);
+        prusti_assert!(list.len() == 2);
+        prusti_assert!(*list.lookup(0) == 10);
+        prusti_assert!(*list.lookup(1) == 5);
+
+        let x = list.pop();
+        prusti_assert!(x == 10);
+        prusti_assert!(list.len() == 1 && *list.lookup(0) == 5);
+    }
+
+    fn _test_list_as_container() {
+        let mut list = List::new();
+        Container::push(&mut list, 5);
+        Container::push(&mut list, 10);
+        prusti_assert!(Container::len(&list) == 2);
+
+        let x = Container::pop(&mut list);
+        prusti_assert!(x == 10);
+    }
+}
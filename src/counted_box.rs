@@ -0,0 +1,171 @@
+use prusti_contracts::*;
+
+use crate::list_generic_with_peek::List;
+
+// A toy model of `Rc`: a value with handles to it, and a count of the handles.
+//
+// The count is ghost state: it only exists when verifying (like ghost bindings, the fields are
+// `cfg(prusti)`), so the model costs nothing at runtime. A `Handle` can't be copied or cloned:
+// only `clone_handle` makes a new one, and it adds one to the count. `drop_handle` gives one
+// back and takes one away. Nothing forces a program to give its handles back, though: a handle
+// that goes out of scope (or is passed to `mem::forget`) leaks, and the count stays as it was.
+// So the count is at least the number of handles around, not exactly that number, and the
+// specs only state how each call changes it. Like a leaked `Rc`, a box with a leaked handle
+// never gets back to a count of 0.
+//
+// Every handle remembers the (ghost) identity of its box, and the box only accepts its own
+// handles. The count is signed, so giving back a handle needs no proof that the count is
+// positive. (It is, as the handle is around, but nothing in the specs says so.)
+pub struct CountedBox<T> {
+    value: T,
+    #[cfg(prusti)]
+    id: usize,
+    #[cfg(prusti)]
+    count: i64,
+}
+
+pub struct Handle {
+    #[cfg(prusti)]
+    box_id: usize,
+}
+
+impl Handle {
+    #[cfg(prusti)]
+    #[pure]
+    pub fn box_id(&self) -> usize {
+        self.box_id
+    }
+}
+
+// A new identity. The result is unspecified, so Prusti can't show that two boxes share one.
+#[cfg(prusti)]
+#[trusted]
+fn fresh_id() -> usize {
+    0
+}
+
+impl<T> CountedBox<T> {
+    // The box starts with one handle, like `Rc::new`
+    #[ensures(result.0.count() == 1)]
+    #[ensures(result.1.box_id() == result.0.id())]
+    pub fn new(value: T) -> (Self, Handle) {
+        #[cfg(prusti)]
+        let id = fresh_id();
+        let counted = CountedBox {
+            value,
+            #[cfg(prusti)]
+            id,
+            #[cfg(prusti)]
+            count: 1,
+        };
+        let handle = Handle {
+            #[cfg(prusti)]
+            box_id: id,
+        };
+        (counted, handle)
+    }
+
+    #[cfg(prusti)]
+    #[pure]
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    #[cfg(prusti)]
+    #[pure]
+    pub fn count(&self) -> i64 {
+        self.count
+    }
+
+    // Only the holder of a handle can make another one
+    #[requires(handle.box_id() == self.id())]
+    #[ensures(result.box_id() == self.id() && self.id() == old(self.id()))]
+    #[ensures(self.count() == old(self.count()) + 1)]
+    // `handle` is only checked by the spec
+    #[allow(unused_variables)]
+    pub fn clone_handle(&mut self, handle: &Handle) -> Handle {
+        #[cfg(prusti)]
+        {
+            self.count += 1;
+        }
+        Handle {
+            #[cfg(prusti)]
+            box_id: self.id,
+        }
+    }
+
+    #[requires(handle.box_id() == self.id())]
+    #[ensures(self.id() == old(self.id()))]
+    #[ensures(self.count() == old(self.count()) - 1)]
+    pub fn drop_handle(&mut self, handle: Handle) {
+        let Handle { .. } = handle;
+        #[cfg(prusti)]
+        {
+            self.count -= 1;
+        }
+    }
+
+    #[pure]
+    #[requires(handle.box_id() == self.id())]
+    // `handle` is only checked by the spec
+    #[allow(unused_variables)]
+    pub fn get(&self, handle: &Handle) -> &T {
+        &self.value
+    }
+}
+
+// Clones `n` more handles: the count grows by exactly the number of handles in the list
+#[requires(handle.box_id() == counted.id())]
+#[ensures(result.len() == n)]
+#[ensures(forall(|i: usize| i < n ==> result.lookup(i).box_id() == counted.id()))]
+#[ensures(counted.id() == old(counted.id()))]
+#[ensures(counted.count() == old(counted.count()) + n as i64)]
+pub fn clone_many<T>(counted: &mut CountedBox<T>, handle: &Handle, n: usize) -> List<Handle> {
+    let mut handles = List::new();
+    while handles.len() < n {
+        body_invariant!(handles.len() < n);
+        body_invariant!(counted.id() == old(counted.id()));
+        body_invariant!(forall(|i: usize| i < handles.len() ==> handles.lookup(i).box_id() == counted.id()));
+        body_invariant!(counted.count() == old(counted.count()) + handles.len() as i64);
+
+        handles.push(counted.clone_handle(handle));
+    }
+    handles
+}
+
+// Gives back every handle in the list
+#[requires(forall(|i: usize| i < handles.len() ==> handles.lookup(i).box_id() == counted.id()))]
+#[ensures(counted.id() == old(counted.id()))]
+#[ensures(counted.count() == old(counted.count()) - old(handles.len()) as i64)]
+pub fn drop_all<T>(counted: &mut CountedBox<T>, mut handles: List<Handle>) {
+    while !handles.is_empty() {
+        body_invariant!(!handles.is_empty());
+        body_invariant!(counted.id() == old(counted.id()));
+        body_invariant!(forall(|i: usize| i < handles.len() ==> handles.lookup(i).box_id() == counted.id()));
+        body_invariant!(counted.count() + old(handles.len()) as i64 == old(counted.count()) + handles.len() as i64);
+
+        counted.drop_handle(handles.pop());
+    }
+}
+
+#[cfg(prusti)]
+mod prusti_tests {
+    use super::*;
+
+    fn _test_counted_box() {
+        let (mut counted, handle) = CountedBox::new(5);
+        let extra = counted.clone_handle(&handle);
+        prusti_assert!(counted.count() == 2);
+
+        let handles = clone_many(&mut counted, &handle, 3);
+        prusti_assert!(counted.count() == 5);
+        drop_all(&mut counted, handles);
+        counted.drop_handle(extra);
+        counted.drop_handle(handle);
+        prusti_assert!(counted.count() == 0);
+
+        let (mut other, other_handle) = CountedBox::new(7);
+        // counted.drop_handle(other_handle); // FAILS: the handle belongs to `other`
+        other.drop_handle(other_handle);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,114 @@
+use prusti_contracts::*;
+
+use crate::binary::pow2;
+// Predicates only exist when verifying
+#[cfg(prusti)]
+use crate::binary::is_binary;
+
+// A binary counter stored least significant digit first (unlike `binary`), so the carry
+// runs from index 0 upwards and a new digit is appended at the end when the counter grows.
+// The counter never wraps around: it gets longer instead, and the only precondition is that
+// its value still fits the `u64` used by the spec.
+
+// Value of the `k` least significant digits
+#[pure]
+#[requires(k <= bits.len() && k < 64)]
+#[cfg_attr(prusti, terminates(Int::new_usize(k)))]
+pub fn value_upto(bits: &[u8], k: usize) -> u64 {
+    if k == 0 {
+        0
+    } else {
+        value_upto(bits, k - 1) + bits[k - 1] as u64 * pow2(k as u32 - 1)
+    }
+}
+
+#[pure]
+#[requires(bits.len() < 64)]
+pub fn value_of(bits: &[u8]) -> u64 {
+    value_upto(bits, bits.len())
+}
+
+// Lemma: digits that are all 0 are worth nothing
+#[pure]
+#[requires(k <= bits.len() && k < 64)]
+#[requires(forall(|j: usize| j < k ==> bits[j] == 0))]
+#[ensures(value_upto(bits, k) == 0)]
+#[cfg_attr(prusti, terminates(Int::new_usize(k)))]
+#[allow(clippy::only_used_in_recursion)]
+fn lemma_zeros(bits: &[u8], k: usize) -> bool {
+    k == 0 || lemma_zeros(bits, k - 1)
+}
+
+// Lemma: `k` digits that are all 1 are one less than the next power of two
+#[pure]
+#[requires(k <= bits.len() && k < 63)]
+#[requires(forall(|j: usize| j < k ==> bits[j] == 1))]
+#[ensures(value_upto(bits, k) + 1 == pow2(k as u32))]
+#[cfg_attr(prusti, terminates(Int::new_usize(k)))]
+#[allow(clippy::only_used_in_recursion)]
+fn lemma_ones(bits: &[u8], k: usize) -> bool {
+    k == 0 || lemma_ones(bits, k - 1)
+}
+
+// Lemma: the digits from `i` to `n` add the same to the value in `a` and `b`
+// if the two agree on them
+#[pure]
+#[requires(i <= n && n <= a.len() && n <= b.len() && n < 64)]
+#[requires(forall(|j: usize| (i <= j && j < n) ==> a[j] == b[j]))]
+#[ensures(value_upto(a, n) + value_upto(b, i) == value_upto(b, n) + value_upto(a, i))]
+#[cfg_attr(prusti, terminates(Int::new_usize(n)))]
+#[allow(clippy::only_used_in_recursion)]
+fn lemma_same_digits(a: &[u8], b: &[u8], i: usize, n: usize) -> bool {
+    n == i || lemma_same_digits(a, b, i, n - 1)
+}
+
+// The trailing 1s become 0s, the first 0 becomes a 1 (or a new 1 is appended)
+#[requires(is_binary(bits.as_slice()))]
+#[requires(bits.len() < 63)]
+#[ensures(is_binary(bits.as_slice()))]
+#[ensures(bits.len() == old(bits.len()) || bits.len() == old(bits.len()) + 1)]
+#[ensures(value_of(bits.as_slice()) == old(value_of(bits.as_slice())) + 1)]
+pub fn increment(bits: &mut Vec<u8>) {
+    #[cfg(prusti)]
+    let before = ghost!(snap(bits));
+    let mut i = 0;
+    while i < bits.len() && bits[i] == 1 {
+        body_invariant!(i < bits.len() && bits.len() == before.len() && bits.len() < 63);
+        body_invariant!(is_binary(bits.as_slice()));
+        body_invariant!(forall(|j: usize| j < i ==> bits[j] == 0 && before[j] == 1));
+        body_invariant!(forall(|j: usize| (i <= j && j < bits.len()) ==> bits[j] == before[j]));
+
+        bits[i] = 0;
+        i += 1;
+    }
+
+    // `i` digits were carried: they were worth `2^i - 1` and are worth nothing now
+    prusti_assert!(lemma_zeros(bits.as_slice(), i) && lemma_ones(before.as_slice(), i));
+    #[cfg(prusti)]
+    let carried = ghost!(snap(bits));
+    if i < bits.len() {
+        // The digit at `i` was 0, and is worth `2^i` now
+        bits[i] = 1;
+        // The digits below `i` are still the carried 0s
+        prusti_assert!(lemma_same_digits(carried.as_slice(), bits.as_slice(), 0, i));
+        prusti_assert!(lemma_same_digits(before.as_slice(), bits.as_slice(), i + 1, bits.len()));
+    } else {
+        // Every digit was 1
+        bits.push(1);
+        prusti_assert!(lemma_same_digits(carried.as_slice(), bits.as_slice(), 0, i));
+    }
+}
+
+#[cfg(prusti)]
+mod prusti_tests {
+    use super::*;
+
+    fn _test_increment() {
+        let mut bits = Vec::new();
+        increment(&mut bits);
+        prusti_assert!(value_of(bits.as_slice()) == 1);
+        increment(&mut bits);
+        increment(&mut bits);
+        prusti_assert!(value_of(bits.as_slice()) == 3);
+    }
+}
\ No newline at end of file
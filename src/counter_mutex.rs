@@ -0,0 +1,87 @@
+use prusti_contracts::*;
+use std::sync::Mutex;
+
+// A counter behind a `Mutex`.
+//
+// Prusti knows nothing about `Mutex` (or threads), so the std functions are called through
+// trusted wrappers whose contracts say what they do to the protected value, in the style of an
+// `#[extern_spec]`. `locked_value` is the value as seen by whoever holds the lock.
+// The wrappers that change the value take `&mut`: Prusti has no other way to know that it
+// changed. In a program with threads this stands for holding the lock between the two calls.
+
+#[trusted]
+#[pure]
+pub fn locked_value<T: Copy>(mutex: &Mutex<T>) -> T {
+    *mutex.lock().unwrap()
+}
+
+#[trusted]
+#[ensures(locked_value(&result) === value)]
+pub fn mutex_new<T: Copy>(value: T) -> Mutex<T> {
+    Mutex::new(value)
+}
+
+#[trusted]
+#[ensures(result === locked_value(mutex))]
+pub fn lock_get<T: Copy>(mutex: &Mutex<T>) -> T {
+    *mutex.lock().unwrap()
+}
+
+// With a `&mut` nobody else can hold the lock, so there is no need to take it
+#[trusted]
+#[ensures(locked_value(mutex) === value)]
+pub fn lock_set<T: Copy>(mutex: &mut Mutex<T>, value: T) {
+    *mutex.get_mut().unwrap() = value;
+}
+
+pub struct VerifiedCounter(Mutex<u64>);
+
+impl VerifiedCounter {
+    #[ensures(result.value() == 0)]
+    pub fn new() -> Self {
+        VerifiedCounter(mutex_new(0))
+    }
+
+    #[pure]
+    pub fn value(&self) -> u64 {
+        locked_value(&self.0)
+    }
+
+    // `Prusti.toml` turns off overflow checks, so the assertion is what shows that the
+    // precondition rules out the overflow: it computes `value + 1` in `u128`
+    #[requires(self.value() < u64::MAX)]
+    #[ensures(self.value() == old(self.value()) + 1)]
+    pub fn increment(&mut self) {
+        let value = lock_get(&self.0);
+        prusti_assert!(value as u128 + 1 <= u64::MAX as u128);
+        lock_set(&mut self.0, value + 1);
+    }
+}
+
+// A counter can be incremented `n` more times if it is at most `u64::MAX - n`.
+// Callers state their number of calls once, instead of checking before every increment.
+#[requires(counter.value() <= u64::MAX - n)]
+#[ensures(counter.value() == old(counter.value()) + n)]
+pub fn count_events(counter: &mut VerifiedCounter, n: u64) {
+    let mut i = 0;
+    while i < n {
+        body_invariant!(i < n);
+        body_invariant!(counter.value() == old(counter.value()) + i);
+
+        counter.increment();
+        i += 1;
+    }
+}
+
+#[cfg(prusti)]
+mod prusti_tests {
+    use super::*;
+
+    fn _test_verified_counter() {
+        let mut counter = VerifiedCounter::new();
+        counter.increment();
+        count_events(&mut counter, 10);
+        prusti_assert!(counter.value() == 11);
+        // count_events(&mut counter, u64::MAX); // FAILS: the counter is at 11, so this would overflow
+    }
+}
\ No newline at end of file
@@ -0,0 +1,133 @@
+use prusti_contracts::*;
+
+use crate::list_generic_with_peek::List;
+use crate::spec_macros::ensures_unchanged;
+
+// A mutable cursor on a `List`: it points at one position and can change the list around
+// that position.
+//
+// The cursor borrows the list. Its view of the list (`len` and `lookup`) is the list itself,
+// so every method only describes its effect on the view, and the pledge of `cursor_mut` hands
+// the last view back: once the cursor expires, the list is exactly what the cursor saw last.
+pub struct CursorMut<'a, T> {
+    list: &'a mut List<T>,
+    index: usize,
+}
+
+impl<T> List<T> {
+    // The pledge is about the list behind the cursor's reference, which Prusti can't connect
+    // to `self` on its own, so it is trusted
+    #[trusted]
+    #[ensures(result.index() == 0)]
+    #[ensures(result.len() == old(self.len()))]
+    #[ensures(forall(|i: usize| i < result.len() ==> result.lookup(i) === old(self.lookup(i))))]
+    #[after_expiry(
+        self.len() == before_expiry(result.len())
+        && forall(|i: usize| i < self.len() ==>
+            snap(self.lookup(i)) === before_expiry(snap(result.lookup(i))))
+    )]
+    pub fn cursor_mut(&mut self) -> CursorMut<'_, T> {
+        CursorMut { list: self, index: 0 }
+    }
+}
+
+impl<T> CursorMut<'_, T> {
+    #[pure]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    #[pure]
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    #[pure]
+    #[requires(i < self.len())]
+    pub fn lookup(&self, i: usize) -> &T {
+        self.list.lookup(i)
+    }
+
+    // Moves to the next position, unless the cursor is already at the last one
+    #[ensures(result == (old(self.index()) + 1 < self.len()))]
+    #[ensures(self.index() == if result { old(self.index()) + 1 } else { old(self.index()) })]
+    #[ensures(self.len() == old(self.len()))]
+    #[ensures(forall(|i: usize| i < self.len() ==> self.lookup(i) === old(self.lookup(i))))]
+    pub fn advance(&mut self) -> bool {
+        if self.index + 1 < self.list.len() {
+            self.index += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    ensures_unchanged! {
+        after_expiry(
+            self.index() == old(self.index())
+            && self.len() == old(self.len())
+            && snap(self.lookup(self.index())) === before_expiry(snap(result))
+        )
+        |i| snap(self.lookup(i)), self.len(), except self.index();
+        #[requires(self.index() < self.len())]
+        #[ensures(snap(result) === old(snap(self.lookup(self.index()))))]
+        pub fn peek_mut(&mut self) -> &mut T {
+            self.list.lookup_mut(self.index)
+        }
+    }
+
+    // Inserts `elem` right behind the cursor, which stays where it is
+    #[requires(self.index() < self.len())]
+    #[ensures(self.index() == old(self.index()))]
+    #[ensures(self.len() == old(self.len()) + 1)]
+    #[ensures(snap(self.lookup(self.index() + 1)) === elem)]
+    #[ensures(forall(|i: usize| i <= self.index() ==> self.lookup(i) === old(self.lookup(i))))]
+    #[ensures(forall(|i: usize| (self.index() < i && i < old(self.len())) ==>
+        self.lookup(i + 1) === old(self.lookup(i))))]
+    pub fn insert_after(&mut self, elem: T) {
+        insert_at(self.list, self.index + 1, elem);
+    }
+
+}
+
+// The counterpart of `List::remove_at`: the elements from `index` on move back by one
+#[requires(index <= list.len())]
+#[ensures(list.len() == old(list.len()) + 1)]
+#[ensures(snap(list.lookup(index)) === elem)]
+#[ensures(forall(|i: usize| i < index ==> list.lookup(i) === old(list.lookup(i))))]
+#[ensures(forall(|i: usize| (index <= i && i < old(list.len())) ==>
+    list.lookup(i + 1) === old(list.lookup(i))))]
+fn insert_at<T>(list: &mut List<T>, index: usize, elem: T) {
+    if index == 0 {
+        list.push(elem);
+    } else {
+        let head = list.pop();
+        insert_at(list, index - 1, elem);
+        list.push(head);
+    }
+}
+
+#[cfg(prusti)]
+mod prusti_tests {
+    use super::*;
+
+    fn _test_cursor_mut() {
+        let mut list = List::new();
+        list.push(3);
+        list.push(2);
+        list.push(1);
+        // The list is [1, 2, 3]
+        let mut cursor = list.cursor_mut();
+        let moved = cursor.advance();
+        prusti_assert!(moved && cursor.index() == 1);
+
+        let elem = cursor.peek_mut();
+        *elem = 7;
+        cursor.insert_after(9);
+        prusti_assert!(cursor.index() == 1 && cursor.len() == 4);
+
+        // The cursor has expired, so the list is back
+        prusti_assert!(list.len() == 4);
+        prusti_assert!(list[0] == 1 && list[1] == 7 && list[2] == 9 && list[3] == 3);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,128 @@
+use prusti_contracts::*;
+use std::marker::PhantomData;
+
+// The same door twice. `Door<S>` tracks its state in the type, so opening an open door
+// doesn't compile. `DynDoor` keeps the state in a field, e.g. because it is only known at
+// runtime; there the illegal transitions are ruled out by preconditions instead, and
+// Prusti rejects the calls that would be type errors for `Door<S>`.
+//
+// Converting between the two is where both kinds of checking meet: a `DynDoor` can only
+// become a `Door<Closed>` if it is provably closed.
+
+pub struct Open;
+pub struct Closed;
+
+pub struct Door<S> {
+    opened: u32,
+    state: PhantomData<S>,
+}
+
+impl<S> Door<S> {
+    #[pure]
+    pub fn times_opened(&self) -> u32 {
+        self.opened
+    }
+}
+
+impl Door<Closed> {
+    #[ensures(result.times_opened() == 0)]
+    pub fn new() -> Self {
+        Door { opened: 0, state: PhantomData }
+    }
+
+    #[ensures(result.times_opened() == self.times_opened() + 1)]
+    pub fn open(self) -> Door<Open> {
+        Door { opened: self.opened + 1, state: PhantomData }
+    }
+}
+
+impl Door<Open> {
+    #[ensures(result.times_opened() == self.times_opened())]
+    pub fn close(self) -> Door<Closed> {
+        Door { opened: self.opened, state: PhantomData }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DoorState {
+    Open,
+    Closed,
+}
+
+pub struct DynDoor {
+    state: DoorState,
+    opened: u32,
+}
+
+impl DynDoor {
+    #[pure]
+    pub fn is_open(&self) -> bool {
+        matches!(self.state, DoorState::Open)
+    }
+
+    #[pure]
+    pub fn times_opened(&self) -> u32 {
+        self.opened
+    }
+
+    #[requires(!self.is_open())]
+    #[ensures(self.is_open())]
+    #[ensures(self.times_opened() == old(self.times_opened()) + 1)]
+    pub fn open(&mut self) {
+        self.state = DoorState::Open;
+        self.opened += 1;
+    }
+
+    #[requires(self.is_open())]
+    #[ensures(!self.is_open())]
+    #[ensures(self.times_opened() == old(self.times_opened()))]
+    pub fn close(&mut self) {
+        self.state = DoorState::Closed;
+    }
+
+    // Only the static door of the right state comes out
+    #[requires(!self.is_open())]
+    #[ensures(result.times_opened() == self.times_opened())]
+    pub fn into_closed(self) -> Door<Closed> {
+        Door { opened: self.opened, state: PhantomData }
+    }
+}
+
+#[refine_trait_spec]
+impl From<Door<Closed>> for DynDoor {
+    #[ensures(!result.is_open())]
+    #[ensures(result.times_opened() == door.times_opened())]
+    fn from(door: Door<Closed>) -> Self {
+        DynDoor { state: DoorState::Closed, opened: door.opened }
+    }
+}
+
+#[refine_trait_spec]
+impl From<Door<Open>> for DynDoor {
+    #[ensures(result.is_open())]
+    #[ensures(result.times_opened() == door.times_opened())]
+    fn from(door: Door<Open>) -> Self {
+        DynDoor { state: DoorState::Open, opened: door.opened }
+    }
+}
+
+#[cfg(prusti)]
+mod prusti_tests {
+    use super::*;
+
+    fn _test_static_door() {
+        let door = Door::new().open().close().open();
+        prusti_assert!(door.times_opened() == 2);
+        // door.open(); // type error: there is no `open` on `Door<Open>`
+    }
+
+    fn _test_dynamic_door() {
+        let mut door = DynDoor::from(Door::new());
+        door.open();
+        door.close();
+        prusti_assert!(door.times_opened() == 1);
+        // door.close(); // FAILS: the door is already closed
+        let back = door.into_closed();
+        prusti_assert!(back.times_opened() == 1);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,73 @@
+use prusti_contracts::*;
+
+// Q16.16 fixed-point numbers: the value of `Fixed(raw)` is `raw / 2^16`.
+// Everything is integer arithmetic on `raw`, which Prusti handles well (unlike floats).
+// The preconditions rule out overflow of the underlying `i32`; the `i64` computations in
+// the specs can't overflow.
+
+pub const ONE: i32 = 1 << 16;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Fixed(i32);
+
+impl Fixed {
+    #[pure]
+    pub fn raw(self) -> i32 {
+        self.0
+    }
+
+    #[requires(-32768 <= n && n < 32768)]
+    #[ensures(result.raw() == n * ONE)]
+    pub fn from_int(n: i32) -> Self {
+        Fixed(n * ONE)
+    }
+
+    // Rounds towards zero, like `as` for floats
+    #[pure]
+    #[ensures(result == self.raw() / ONE)]
+    pub fn to_int(self) -> i32 {
+        self.0 / ONE
+    }
+
+    #[requires(i32::MIN as i64 <= self.raw() as i64 + other.raw() as i64)]
+    #[requires(self.raw() as i64 + other.raw() as i64 <= i32::MAX as i64)]
+    #[ensures(result.raw() == self.raw() + other.raw())]
+    pub fn add(self, other: Fixed) -> Fixed {
+        Fixed(self.0 + other.0)
+    }
+
+    #[requires(i32::MIN as i64 <= self.raw() as i64 - other.raw() as i64)]
+    #[requires(self.raw() as i64 - other.raw() as i64 <= i32::MAX as i64)]
+    #[ensures(result.raw() == self.raw() - other.raw())]
+    pub fn sub(self, other: Fixed) -> Fixed {
+        Fixed(self.0 - other.0)
+    }
+
+    // The product of the raw values has 32 fractional bits, 16 of which are dropped
+    // (rounding towards zero). It is computed in `i64`, so only the result has to fit.
+    #[requires(i32::MIN as i64 <= self.raw() as i64 * other.raw() as i64 / ONE as i64)]
+    #[requires(self.raw() as i64 * other.raw() as i64 / ONE as i64 <= i32::MAX as i64)]
+    #[ensures(result.raw() as i64 == self.raw() as i64 * other.raw() as i64 / ONE as i64)]
+    pub fn mul(self, other: Fixed) -> Fixed {
+        Fixed((self.0 as i64 * other.0 as i64 / ONE as i64) as i32)
+    }
+}
+
+#[cfg(prusti)]
+mod prusti_tests {
+    use super::*;
+
+    fn _test_fixed_point() {
+        let two = Fixed::from_int(2);
+        let three = Fixed::from_int(3);
+        let six = two.mul(three);
+        prusti_assert!(six.to_int() == 6);
+
+        let five = two.add(three);
+        prusti_assert!(five.raw() == 5 * ONE);
+        let minus_one = two.sub(three);
+        prusti_assert!(minus_one.to_int() == -1);
+
+        // Fixed::from_int(40000); // FAILS: too large for 16 integer bits
+    }
+}
\ No newline at end of file
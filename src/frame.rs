@@ -0,0 +1,90 @@
+use prusti_contracts::*;
+
+use crate::spec_macros::ensures_unchanged;
+
+// Frame conditions ("nothing else changed") for fixed-size arrays, the counterpart
+// of `List::unchanged`/`List::unchanged_except`. Slices would be more general, but
+// they are unsized, so `old(snap(..))` can't take a copy of them.
+
+predicate! {
+    pub fn array_unchanged<T, const N: usize>(a: &[T; N], prev: &[T; N]) -> bool {
+        forall(|i: usize| i < N ==> a[i] === prev[i])
+    }
+}
+
+predicate! {
+    // Only the element at `index` may differ from `prev`
+    pub fn array_unchanged_except<T, const N: usize>(a: &[T; N], prev: &[T; N], index: usize) -> bool {
+        forall(|i: usize| (i < N && i != index) ==> a[i] === prev[i])
+    }
+}
+
+#[requires(index < N)]
+#[ensures(a[index] === value)]
+#[ensures(array_unchanged_except(a, &old(snap(a)), index))]
+pub fn set<T, const N: usize>(a: &mut [T; N], index: usize, value: T) {
+    a[index] = value;
+}
+
+#[requires(index < N)]
+#[ensures(result === a[index])]
+#[ensures(array_unchanged(a, &old(snap(a))))]
+pub fn get<T: Copy, const N: usize>(a: &[T; N], index: usize) -> T {
+    a[index]
+}
+
+// Fixed-size storage whose elements can only be changed one at a time through `set`,
+// so every change comes with the frame condition. Meant as the storage layer of other
+// containers, which then don't have to repeat the array reasoning.
+pub struct Fixed<T: Copy, const N: usize> {
+    elems: [T; N],
+}
+
+impl<T: Copy, const N: usize> Fixed<T, N> {
+    #[ensures(forall(|i: usize| i < N ==> result.get(i) === value))]
+    pub fn new(value: T) -> Self {
+        Fixed { elems: [value; N] }
+    }
+
+    #[pure]
+    pub fn len(&self) -> usize {
+        N
+    }
+
+    #[pure]
+    #[requires(index < N)]
+    pub fn get(&self, index: usize) -> T {
+        self.elems[index]
+    }
+
+    ensures_unchanged! {
+        |i| self.get(i), N, except index;
+        #[requires(index < N)]
+        #[ensures(self.get(index) === value)]
+        pub fn set(&mut self, index: usize, value: T) {
+            set(&mut self.elems, index, value);
+        }
+    }
+}
+
+#[cfg(prusti)]
+mod prusti_tests {
+    use super::*;
+
+    fn _test_set() {
+        let mut a = [1, 2, 3];
+        set(&mut a, 1, 5);
+        prusti_assert!(a[0] == 1 && a[1] == 5 && a[2] == 3);
+
+        let x = get(&a, 2);
+        prusti_assert!(x == 3);
+    }
+
+    fn _test_fixed() {
+        let mut storage: Fixed<i32, 4> = Fixed::new(0);
+        storage.set(2, 7);
+        prusti_assert!(storage.get(2) == 7);
+        prusti_assert!(storage.get(0) == 0 && storage.get(3) == 0);
+        // storage.set(4, 1); // FAILS: index out of bounds
+    }
+}
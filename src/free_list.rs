@@ -0,0 +1,76 @@
+use prusti_contracts::*;
+
+// Allocation of the indices `0..N`, e.g. for the slots of a fixed-size pool.
+//
+// `alloc` only hands out indices that aren't live, so no index is given to two owners at the
+// same time, and `free` requires a live index, so a double free doesn't verify. The free
+// indices are found by scanning a bitmap. A classic free list (a stack of the free indices)
+// would need the invariant that it holds every free index exactly once, and that `free` can
+// never find the stack full is a counting argument the solver doesn't find on its own.
+pub struct FreeList<const N: usize> {
+    live: [bool; N],
+}
+
+impl<const N: usize> FreeList<N> {
+    #[ensures(forall(|i: usize| i < N ==> !result.is_live(i)))]
+    pub fn new() -> Self {
+        FreeList { live: [false; N] }
+    }
+
+    #[pure]
+    #[requires(i < N)]
+    pub fn is_live(&self, i: usize) -> bool {
+        self.live[i]
+    }
+
+    #[ensures(match result {
+        Some(i) => i < N && !old(self.is_live(i)) && self.is_live(i),
+        None => forall(|i: usize| i < N ==> self.is_live(i)),
+    })]
+    #[ensures(forall(|i: usize| (i < N && result !== Some(i)) ==> self.is_live(i) == old(self.is_live(i))))]
+    pub fn alloc(&mut self) -> Option<usize> {
+        let mut i = 0;
+        while i < N {
+            body_invariant!(i < N);
+            body_invariant!(forall(|j: usize| j < i ==> self.live[j]));
+            body_invariant!(forall(|j: usize| j < N ==> self.live[j] == old(self.live[j])));
+
+            if !self.live[i] {
+                self.live[i] = true;
+                return Some(i);
+            }
+            i += 1;
+        }
+        None
+    }
+
+    // The index can be handed out again afterwards
+    #[requires(i < N && self.is_live(i))]
+    #[ensures(!self.is_live(i))]
+    #[ensures(forall(|j: usize| (j < N && j != i) ==> self.is_live(j) == old(self.is_live(j))))]
+    pub fn free(&mut self, i: usize) {
+        self.live[i] = false;
+    }
+}
+
+#[cfg(prusti)]
+mod prusti_tests {
+    use super::*;
+
+    fn _test_free_list() {
+        let mut list: FreeList<2> = FreeList::new();
+        let a = list.alloc();
+        let b = list.alloc();
+        let c = list.alloc();
+        prusti_assert!(a.is_some() && b.is_some() && c.is_none());
+        // Both are live at the same time, so they are different
+        prusti_assert!(a !== b);
+
+        if let Some(i) = a {
+            list.free(i);
+            // list.free(i); // FAILS: double free
+            let d = list.alloc();
+            prusti_assert!(d === Some(i));
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,251 @@
+use prusti_contracts::*;
+
+use crate::list_generic_with_peek::List;
+use crate::matrix::Matrix;
+
+// Graph algorithms. Node indices are plain `usize`s, so most of the work is showing
+// that every index used is in bounds.
+
+predicate! {
+    // `to` can be reached from `from` in at most `k` steps along the edges of `adj`
+    pub fn reachable_within<const N: usize>(adj: &Matrix<bool, N, N>, from: usize, to: usize, k: usize) -> bool {
+        from == to || (k > 0 && exists(|p: usize| p < N && adj.get(p, to)
+            && reachable_within(adj, from, p, k - 1)))
+    }
+}
+
+predicate! {
+    pub fn reaches<const N: usize>(adj: &Matrix<bool, N, N>, from: usize, to: usize) -> bool {
+        exists(|k: usize| reachable_within(adj, from, to, k))
+    }
+}
+
+// Worklist search from `from`. Every node enters the worklist at most once, so `N` rounds
+// are enough; the fuel makes that bound explicit instead of relying on a termination argument.
+// Only soundness is proven: a node is only marked once an edge from a marked node leads to it.
+// (Completeness would need a counting argument about the marked nodes.)
+#[requires(from < N && to < N)]
+#[ensures(result ==> reaches(adj, from, to))]
+pub fn reachable<const N: usize>(adj: &Matrix<bool, N, N>, from: usize, to: usize) -> bool {
+    let mut visited = [false; N];
+    let mut worklist = [0; N];
+    visited[from] = true;
+    worklist[0] = from;
+    let mut len = 1;
+    prusti_assert!(reachable_within(adj, from, from, 0));
+
+    let mut fuel = N;
+    while len > 0 && fuel > 0 {
+        body_invariant!(0 < len && len <= N);
+        body_invariant!(forall(|i: usize| i < len ==> worklist[i] < N && visited[worklist[i]]));
+        body_invariant!(forall(|m: usize| (m < N && visited[m]) ==> reaches(adj, from, m)));
+
+        len -= 1;
+        fuel -= 1;
+        let p = worklist[len];
+        let mut q = 0;
+        while q < N {
+            body_invariant!(q < N && len <= N && p < N && visited[p]);
+            body_invariant!(forall(|i: usize| i < len ==> worklist[i] < N && visited[worklist[i]]));
+            body_invariant!(forall(|m: usize| (m < N && visited[m]) ==> reaches(adj, from, m)));
+
+            if adj.get(p, q) && !visited[q] && len < N {
+                // One more step than the path to `p`
+                prusti_assert!(forall(|k: usize| reachable_within(adj, from, p, k) ==>
+                    reachable_within(adj, from, q, k + 1)));
+                visited[q] = true;
+                worklist[len] = q;
+                len += 1;
+            }
+            q += 1;
+        }
+    }
+
+    visited[to]
+}
+
+// Adjacency lists: `adj[n]` holds the nodes that `n` has an edge to.
+// The invariant is what lets algorithms index with a neighbour without further checks.
+#[invariant(forall(|n: usize, i: usize| (n < self.adj.len() && i < self.adj[n].len()) ==>
+    *self.adj[n].lookup(i) < self.adj.len()))]
+pub struct Graph {
+    adj: Vec<List<usize>>,
+}
+
+impl Graph {
+    // `nodes` nodes and no edges
+    #[ensures(result.node_count() == nodes)]
+    #[ensures(forall(|n: usize| n < nodes ==> result.degree(n) == 0))]
+    pub fn new(nodes: usize) -> Self {
+        let mut adj = Vec::new();
+        while adj.len() < nodes {
+            body_invariant!(adj.len() < nodes);
+            body_invariant!(forall(|n: usize| n < adj.len() ==> adj[n].len() == 0));
+            adj.push(List::new());
+        }
+        Graph { adj }
+    }
+
+    #[pure]
+    pub fn node_count(&self) -> usize {
+        self.adj.len()
+    }
+
+    #[pure]
+    #[requires(node < self.node_count())]
+    pub fn degree(&self, node: usize) -> usize {
+        self.adj[node].len()
+    }
+
+    // The `i`-th neighbour of `node`, always a valid node itself
+    #[pure]
+    #[requires(node < self.node_count() && i < self.degree(node))]
+    #[ensures(result < self.node_count())]
+    pub fn neighbor(&self, node: usize, i: usize) -> usize {
+        *self.adj[node].lookup(i)
+    }
+
+    #[requires(node < self.node_count())]
+    #[ensures(result.len() == self.degree(node))]
+    #[ensures(forall(|i: usize| i < result.len() ==>
+        *result.lookup(i) == self.neighbor(node, i) && *result.lookup(i) < self.node_count()))]
+    pub fn neighbors(&self, node: usize) -> &List<usize> {
+        &self.adj[node]
+    }
+
+    // The new edge becomes the first neighbour of `from`; nothing else changes
+    #[requires(from < self.node_count() && to < self.node_count())]
+    #[ensures(self.node_count() == old(self.node_count()))]
+    #[ensures(self.degree(from) == old(self.degree(from)) + 1)]
+    #[ensures(self.neighbor(from, 0) == to)]
+    #[ensures(forall(|i: usize| i < old(self.degree(from)) ==>
+        self.neighbor(from, i + 1) == old(self.neighbor(from, i))))]
+    #[ensures(forall(|n: usize| (n < self.node_count() && n != from) ==>
+        self.degree(n) == old(self.degree(n))))]
+    #[ensures(forall(|n: usize, i: usize| (n < self.node_count() && n != from && i < self.degree(n)) ==>
+        self.neighbor(n, i) == old(self.neighbor(n, i))))]
+    pub fn add_edge(&mut self, from: usize, to: usize) {
+        self.adj[from].push(to);
+    }
+}
+
+// Kahn's algorithm. Whether the order really respects every edge (and `None` really means
+// a cycle) depends on the in-degree bookkeeping, which is trusted. What is verified is the
+// shape of the result: every node exactly once.
+
+// Number of edges into each node
+#[trusted]
+#[ensures(result.len() == graph.node_count())]
+fn in_degrees(graph: &Graph) -> Vec<usize> {
+    let mut degrees = vec![0; graph.node_count()];
+    for node in 0..graph.node_count() {
+        for i in 0..graph.degree(node) {
+            degrees[graph.neighbor(node, i)] += 1;
+        }
+    }
+    degrees
+}
+
+// Removes the edges out of `node`
+#[trusted]
+#[requires(node < graph.node_count() && degrees.len() == graph.node_count())]
+#[ensures(degrees.len() == old(degrees.len()))]
+// A `Vec` so that the caller keeps knowing its length (Prusti has no spec for `DerefMut`)
+#[allow(clippy::ptr_arg)]
+fn release(graph: &Graph, node: usize, degrees: &mut Vec<usize>) {
+    for i in 0..graph.degree(node) {
+        degrees[graph.neighbor(node, i)] -= 1;
+    }
+}
+
+// A node that wasn't emitted yet and has no incoming edges left
+#[requires(emitted.len() == degrees.len())]
+#[ensures(match result {
+    Some(node) => node < emitted.len() && !emitted[node] && degrees[node] == 0,
+    None => true,
+})]
+fn find_ready(emitted: &[bool], degrees: &[usize]) -> Option<usize> {
+    let mut node = 0;
+    while node < emitted.len() {
+        body_invariant!(node < emitted.len());
+        if !emitted[node] && degrees[node] == 0 {
+            return Some(node);
+        }
+        node += 1;
+    }
+    None
+}
+
+#[ensures(match result {
+    Some(order) => order.len() == graph.node_count()
+        && forall(|i: usize| i < order.len() ==> order[i] < graph.node_count())
+        && forall(|i: usize, j: usize| (i < j && j < order.len()) ==> order[i] != order[j]),
+    None => true,
+})]
+pub fn topo_sort(graph: &Graph) -> Option<Vec<usize>> {
+    let n = graph.node_count();
+    let mut degrees = in_degrees(graph);
+    let mut emitted = Vec::new();
+    while emitted.len() < n {
+        body_invariant!(emitted.len() < n);
+        body_invariant!(forall(|m: usize| m < emitted.len() ==> !emitted[m]));
+        emitted.push(false);
+    }
+
+    let mut order = Vec::new();
+    while order.len() < n {
+        body_invariant!(order.len() < n && emitted.len() == n && degrees.len() == n);
+        body_invariant!(forall(|i: usize| i < order.len() ==> order[i] < n && emitted[order[i]]));
+        body_invariant!(forall(|i: usize, j: usize| (i < j && j < order.len()) ==> order[i] != order[j]));
+
+        match find_ready(emitted.as_slice(), degrees.as_slice()) {
+            // Every remaining node has an incoming edge: there is a cycle
+            None => return None,
+            Some(node) => {
+                // `node` wasn't emitted, so it differs from everything in `order`
+                emitted[node] = true;
+                order.push(node);
+                release(graph, node, &mut degrees);
+            }
+        }
+    }
+    Some(order)
+}
+
+#[cfg(prusti)]
+mod prusti_tests {
+    use super::*;
+
+    fn _test_reachable() {
+        // 0 -> 1 -> 2, and 3 on its own
+        let mut adj: Matrix<bool, 4, 4> = Matrix::new(false);
+        adj.set(0, 1, true);
+        adj.set(1, 2, true);
+
+        let found = reachable(&adj, 0, 2);
+        prusti_assert!(found ==> reaches(&adj, 0, 2));
+        // reachable(&adj, 0, 4); // FAILS: there is no node 4
+    }
+
+    fn _test_graph() {
+        let mut graph = Graph::new(3);
+        graph.add_edge(0, 1);
+        graph.add_edge(0, 2);
+        prusti_assert!(graph.degree(0) == 2 && graph.degree(1) == 0);
+        prusti_assert!(graph.neighbor(0, 0) == 2 && graph.neighbor(0, 1) == 1);
+        // graph.add_edge(0, 3); // FAILS: there is no node 3
+    }
+
+    fn _test_topo_sort() {
+        let mut graph = Graph::new(3);
+        graph.add_edge(2, 0);
+        graph.add_edge(0, 1);
+        match topo_sort(&graph) {
+            Some(order) => {
+                prusti_assert!(order.len() == 3);
+                prusti_assert!(order[0] < 3 && order[0] != order[1]);
+            }
+            None => {}
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,127 @@
+use prusti_contracts::*;
+
+// A three-way handshake between a client and a server, as a state machine over both peers.
+//
+//     client: SYN     -> server    (client Init        -> SynSent)
+//     server: SYN-ACK -> client    (server Init        -> SynReceived)
+//     client: ACK     -> server    (client SynSent     -> Established)
+//                                  (server SynReceived -> Established)
+//
+// Each method is one message arriving, and its precondition is the state the receiver must be
+// in for that message. A message that arrives out of order (an ACK before the SYN-ACK, a second
+// SYN) violates a precondition. The type invariant lists the pairs of states that can occur
+// together, and the two-state predicate `one_step_from` says every transition moves exactly one
+// peer exactly one step forward.
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PeerState {
+    Init,
+    SynSent,
+    SynReceived,
+    Established,
+}
+
+// How far a peer got. The client skips `SynReceived`, the server skips `SynSent`.
+#[pure]
+fn rank(state: PeerState) -> u32 {
+    match state {
+        PeerState::Init => 0,
+        PeerState::SynSent | PeerState::SynReceived => 1,
+        PeerState::Established => 2,
+    }
+}
+
+#[invariant(self.compatible())]
+pub struct Handshake {
+    client: PeerState,
+    server: PeerState,
+}
+
+impl Handshake {
+    #[ensures(result.client() === PeerState::Init && result.server() === PeerState::Init)]
+    pub fn new() -> Self {
+        Handshake { client: PeerState::Init, server: PeerState::Init }
+    }
+
+    #[pure]
+    pub fn client(&self) -> PeerState {
+        self.client
+    }
+
+    #[pure]
+    pub fn server(&self) -> PeerState {
+        self.server
+    }
+
+    // The pairs of states that a correct exchange passes through
+    #[pure]
+    pub fn compatible(&self) -> bool {
+        matches!(
+            (self.client, self.server),
+            (PeerState::Init, PeerState::Init)
+                | (PeerState::SynSent, PeerState::Init)
+                | (PeerState::SynSent, PeerState::SynReceived)
+                | (PeerState::Established, PeerState::SynReceived)
+                | (PeerState::Established, PeerState::Established)
+        )
+    }
+
+    #[pure]
+    pub fn is_established(&self) -> bool {
+        matches!((self.client, self.server), (PeerState::Established, PeerState::Established))
+    }
+
+    predicate! {
+        // two-state predicate: nobody went back, and exactly one peer made one step
+        pub fn one_step_from(&self, prev: &Self) -> bool {
+            rank(self.client()) >= rank(prev.client())
+            && rank(self.server()) >= rank(prev.server())
+            && rank(self.client()) + rank(self.server()) == rank(prev.client()) + rank(prev.server()) + 1
+        }
+    }
+
+    #[requires(self.client() === PeerState::Init)]
+    #[ensures(self.client() === PeerState::SynSent)]
+    #[ensures(self.one_step_from(&old(snap(self))))]
+    pub fn send_syn(&mut self) {
+        self.client = PeerState::SynSent;
+    }
+
+    // The server only answers a SYN the client actually sent
+    #[requires(self.client() === PeerState::SynSent && self.server() === PeerState::Init)]
+    #[ensures(self.server() === PeerState::SynReceived)]
+    #[ensures(self.one_step_from(&old(snap(self))))]
+    pub fn receive_syn(&mut self) {
+        self.server = PeerState::SynReceived;
+    }
+
+    #[requires(self.client() === PeerState::SynSent && self.server() === PeerState::SynReceived)]
+    #[ensures(self.client() === PeerState::Established)]
+    #[ensures(self.one_step_from(&old(snap(self))))]
+    pub fn receive_syn_ack(&mut self) {
+        self.client = PeerState::Established;
+    }
+
+    #[requires(self.client() === PeerState::Established && self.server() === PeerState::SynReceived)]
+    #[ensures(self.is_established())]
+    #[ensures(self.one_step_from(&old(snap(self))))]
+    pub fn receive_ack(&mut self) {
+        self.server = PeerState::Established;
+    }
+}
+
+#[cfg(prusti)]
+mod prusti_tests {
+    use super::*;
+
+    fn _test_handshake() {
+        let mut hs = Handshake::new();
+        hs.send_syn();
+        hs.receive_syn();
+        // hs.receive_ack(); // FAILS: the ACK can't overtake the SYN-ACK
+        hs.receive_syn_ack();
+        hs.receive_ack();
+        prusti_assert!(hs.is_established());
+        // hs.send_syn(); // FAILS: the client already is past `Init`
+    }
+}
\ No newline at end of file
@@ -0,0 +1,65 @@
+use prusti_contracts::*;
+
+pub const BUCKETS: usize = 16;
+
+// Sum of the first `k` buckets
+#[pure]
+#[requires(k <= BUCKETS)]
+#[cfg_attr(prusti, terminates(Int::new_usize(k)))]
+pub fn total_upto(buckets: &[u32; BUCKETS], k: usize) -> u64 {
+    if k == 0 {
+        0
+    } else {
+        total_upto(buckets, k - 1) + buckets[k - 1] as u64
+    }
+}
+
+#[pure]
+pub fn total(buckets: &[u32; BUCKETS]) -> u64 {
+    total_upto(buckets, BUCKETS)
+}
+
+// Lemma: incrementing one bucket increments the total of every prefix that contains it
+#[pure]
+#[requires(j < BUCKETS && k <= BUCKETS)]
+#[requires(after[j] as u64 == before[j] as u64 + 1)]
+#[requires(forall(|i: usize| (i < BUCKETS && i != j) ==> after[i] == before[i]))]
+#[ensures(total_upto(after, k) == total_upto(before, k) + if j < k { 1 } else { 0 })]
+#[cfg_attr(prusti, terminates(Int::new_usize(k)))]
+#[allow(clippy::only_used_in_recursion)]
+fn lemma_increment(before: &[u32; BUCKETS], after: &[u32; BUCKETS], j: usize, k: usize) -> bool {
+    k == 0 || lemma_increment(before, after, j, k - 1)
+}
+
+// Counts every byte in the bucket `byte / 16`. The buckets keep their previous counts,
+// so a histogram can be built from several slices. The precondition rules out overflow:
+// no bucket can get larger than the total.
+#[requires(total(buckets) + s.len() as u64 <= u32::MAX as u64)]
+#[ensures(total(buckets) == old(total(buckets)) + s.len() as u64)]
+pub fn histogram(s: &[u8], buckets: &mut [u32; BUCKETS]) {
+    let mut i = 0;
+    while i < s.len() {
+        body_invariant!(i < s.len());
+        body_invariant!(total(buckets) == old(total(buckets)) + i as u64);
+
+        // A byte is at most 255, so this is at most 15: always a valid index
+        let bucket = (s[i] / 16) as usize;
+        #[cfg(prusti)]
+        let before = ghost!(*buckets);
+        buckets[bucket] += 1;
+        prusti_assert!(lemma_increment(&before, buckets, bucket, BUCKETS));
+        i += 1;
+    }
+}
+
+#[cfg(prusti)]
+mod prusti_tests {
+    use super::*;
+
+    fn _test_histogram() {
+        let mut buckets = [0; BUCKETS];
+        prusti_assert!(total(&buckets) == 0);
+        histogram(&[0, 17, 255, 3], &mut buckets);
+        prusti_assert!(total(&buckets) == 4);
+    }
+}
\ No newline at end of file
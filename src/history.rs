@@ -0,0 +1,143 @@
+use prusti_contracts::*;
+
+use crate::list_generic_with_peek::List;
+
+// Undo/redo history: the current state plus two stacks of states.
+// `undo` moves the current state onto the redo stack and restores the last undone-to state,
+// `redo` does the opposite. Neither loses or duplicates a state, so the number of
+// stored states stays the same, and one undone step can always be redone exactly.
+pub struct History<T> {
+    current: T,
+    undo: List<T>,
+    redo: List<T>,
+}
+
+impl<T> History<T> {
+    #[ensures(snap(result.current()) === initial)]
+    #[ensures(result.undo_len() == 0 && result.redo_len() == 0)]
+    pub fn new(initial: T) -> Self {
+        History { current: initial, undo: List::new(), redo: List::new() }
+    }
+
+    #[pure]
+    pub fn current(&self) -> &T {
+        &self.current
+    }
+
+    #[pure]
+    pub fn undo_len(&self) -> usize {
+        self.undo.len()
+    }
+
+    #[pure]
+    pub fn redo_len(&self) -> usize {
+        self.redo.len()
+    }
+
+    // Number of states besides the current one
+    #[pure]
+    pub fn stored(&self) -> usize {
+        self.undo_len() + self.redo_len()
+    }
+
+    // Index 0 is the state `undo` goes back to
+    #[pure]
+    #[requires(index < self.undo_len())]
+    pub fn undo_at(&self, index: usize) -> &T {
+        self.undo.lookup(index)
+    }
+
+    // Index 0 is the state `redo` goes forward to
+    #[pure]
+    #[requires(index < self.redo_len())]
+    pub fn redo_at(&self, index: usize) -> &T {
+        self.redo.lookup(index)
+    }
+
+    predicate! {
+        // two-state predicate: the same current state and the same states on both stacks
+        pub fn same_as(&self, prev: &Self) -> bool {
+            snap(self.current()) === snap(prev.current())
+            && self.undo.unchanged(&prev.undo)
+            && self.redo.unchanged(&prev.redo)
+        }
+    }
+
+    // A new state invalidates everything that was undone
+    #[ensures(snap(self.current()) === state)]
+    #[ensures(self.undo_len() == old(self.undo_len()) + 1)]
+    #[ensures(snap(self.undo_at(0)) === old(snap(self.current())))]
+    #[ensures(forall(|i: usize| i < old(self.undo_len()) ==>
+        self.undo_at(i + 1) === old(self.undo_at(i))))]
+    #[ensures(self.redo_len() == 0)]
+    pub fn record(&mut self, state: T) {
+        let prev = std::mem::replace(&mut self.current, state);
+        self.undo.push(prev);
+        self.redo = List::new();
+    }
+
+    #[requires(self.undo_len() > 0)]
+    #[ensures(self.undo_len() == old(self.undo_len()) - 1)]
+    #[ensures(self.redo_len() == old(self.redo_len()) + 1)]
+    #[ensures(self.stored() == old(self.stored()))]
+    #[ensures(snap(self.current()) === old(snap(self.undo_at(0))))]
+    #[ensures(snap(self.redo_at(0)) === old(snap(self.current())))]
+    #[ensures(forall(|i: usize| i < self.undo_len() ==>
+        self.undo_at(i) === old(self.undo_at(i + 1))))]
+    #[ensures(forall(|i: usize| i < old(self.redo_len()) ==>
+        self.redo_at(i + 1) === old(self.redo_at(i))))]
+    pub fn undo(&mut self) {
+        let prev = self.undo.pop();
+        let undone = std::mem::replace(&mut self.current, prev);
+        self.redo.push(undone);
+    }
+
+    #[requires(self.redo_len() > 0)]
+    #[ensures(self.redo_len() == old(self.redo_len()) - 1)]
+    #[ensures(self.undo_len() == old(self.undo_len()) + 1)]
+    #[ensures(self.stored() == old(self.stored()))]
+    #[ensures(snap(self.current()) === old(snap(self.redo_at(0))))]
+    #[ensures(snap(self.undo_at(0)) === old(snap(self.current())))]
+    #[ensures(forall(|i: usize| i < self.redo_len() ==>
+        self.redo_at(i) === old(self.redo_at(i + 1))))]
+    #[ensures(forall(|i: usize| i < old(self.undo_len()) ==>
+        self.undo_at(i + 1) === old(self.undo_at(i))))]
+    pub fn redo(&mut self) {
+        let next = self.redo.pop();
+        let redone = std::mem::replace(&mut self.current, next);
+        self.undo.push(redone);
+    }
+}
+
+// Undoing and redoing a step gets back to exactly the same history
+#[requires(history.undo_len() > 0)]
+#[ensures(history.same_as(&old(snap(history))))]
+pub fn undo_then_redo<T>(history: &mut History<T>) {
+    history.undo();
+    history.redo();
+}
+
+#[cfg(prusti)]
+mod prusti_tests {
+    use super::*;
+
+    fn _test_history() {
+        let mut history = History::new(0);
+        history.record(1);
+        history.record(2);
+        prusti_assert!(*history.current() == 2 && history.stored() == 2);
+
+        history.undo();
+        prusti_assert!(*history.current() == 1 && history.stored() == 2);
+        history.undo();
+        prusti_assert!(*history.current() == 0);
+        // history.undo(); // FAILS: nothing left to undo
+
+        history.redo();
+        prusti_assert!(*history.current() == 1);
+
+        // Recording drops the redo stack
+        history.record(5);
+        prusti_assert!(history.redo_len() == 0);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,121 @@
+use prusti_contracts::*;
+
+// The specifications below use Prusti's mathematical integer type `Int`, which can't overflow.
+// So they describe what the sum *should* be, and the only place overflow shows up is in
+// the precondition of the machine-integer implementation. `Prusti.toml` turns off overflow
+// checks, so the loops assert that each addition stays within `i64`, computing it in `Int`.
+//
+// `Int` values can't be created by running code, so the spec functions only exist for Prusti.
+
+// Sum of `s[..k]`
+#[cfg(prusti)]
+#[pure]
+#[requires(k <= s.len())]
+#[terminates(Int::new_usize(k))]
+fn sum_spec(s: &[i32], k: usize) -> Int {
+    if k == 0 {
+        Int::new(0)
+    } else {
+        sum_spec(s, k - 1) + Int::new(s[k - 1] as i64)
+    }
+}
+
+// Each element is at most 2^31 in absolute value, so with at most 2^32 elements
+// the sum stays within 2^63 and fits into an `i64`
+// (A `u64`: `2^32` doesn't fit into a `usize` on 32-bit targets.)
+pub const MAX_LEN: u64 = 1 << 32;
+
+#[requires(s.len() as u64 <= MAX_LEN)]
+#[ensures(Int::new(result) == sum_spec(s, s.len()))]
+pub fn sum(s: &[i32]) -> i64 {
+    let mut acc: i64 = 0;
+    let mut i = 0;
+
+    while i < s.len() {
+        body_invariant!(i < s.len());
+        body_invariant!(Int::new(acc) == sum_spec(s, i));
+        // Together with `MAX_LEN`, this is what rules out overflow of the next addition
+        body_invariant!(-Int::new(1 << 31) * Int::new_usize(i) <= Int::new(acc));
+        body_invariant!(Int::new(acc) <= Int::new(1 << 31) * Int::new_usize(i));
+
+        prusti_assert!(Int::new(i64::MIN) <= Int::new(acc) + Int::new(s[i] as i64));
+        prusti_assert!(Int::new(acc) + Int::new(s[i] as i64) <= Int::new(i64::MAX));
+        acc += s[i] as i64;
+        i += 1;
+    }
+
+    acc
+}
+
+// Rust's `/` rounds towards zero, while the spec states it separately for both signs
+#[requires(s.len() > 0 && s.len() as u64 <= MAX_LEN)]
+#[ensures(sum_spec(s, s.len()) >= Int::new(0) ==>
+    Int::new(result) == sum_spec(s, s.len()) / Int::new_usize(s.len()))]
+#[ensures(sum_spec(s, s.len()) < Int::new(0) ==>
+    -Int::new(result) == (-sum_spec(s, s.len())) / Int::new_usize(s.len()))]
+pub fn average(s: &[i32]) -> i64 {
+    sum(s) / s.len() as i64
+}
+
+// The mean always lies between the smallest and the largest element.
+// The loop tracks both, and the accumulator stays between `i * min` and `i * max`,
+// which also bounds it as in `sum`.
+#[requires(s.len() > 0 && s.len() as u64 <= MAX_LEN)]
+#[ensures(exists(|i: usize| i < s.len() && s[i] <= result))]
+#[ensures(exists(|i: usize| i < s.len() && result <= s[i]))]
+pub fn mean(s: &[i32]) -> i32 {
+    let mut acc: i64 = 0;
+    let mut min = s[0];
+    let mut max = s[0];
+    let mut i = 0;
+
+    while i < s.len() {
+        body_invariant!(i < s.len());
+        body_invariant!(exists(|j: usize| j < s.len() && s[j] == min));
+        body_invariant!(exists(|j: usize| j < s.len() && s[j] == max));
+        body_invariant!(Int::new(min as i64) * Int::new_usize(i) <= Int::new(acc));
+        body_invariant!(Int::new(acc) <= Int::new(max as i64) * Int::new_usize(i));
+
+        let x = s[i];
+        if x < min {
+            min = x;
+        }
+        if x > max {
+            max = x;
+        }
+        prusti_assert!(Int::new(i64::MIN) <= Int::new(acc) + Int::new(x as i64));
+        prusti_assert!(Int::new(acc) + Int::new(x as i64) <= Int::new(i64::MAX));
+        acc += x as i64;
+        i += 1;
+    }
+
+    // Division rounds towards zero, which stays between `min` and `max` as both are integers
+    (acc / s.len() as i64) as i32
+}
+
+#[cfg(prusti)]
+mod prusti_tests {
+    use super::*;
+
+    fn _test_sum() {
+        let s = [1, 2, 3, 4];
+        let total = sum(&s);
+        prusti_assert!(total == 10);
+
+        let avg = average(&s);
+        prusti_assert!(avg == 2);
+    }
+
+    fn _test_large_values() {
+        // The sum doesn't fit into an `i32`, but the `i64` accumulator handles it
+        let s = [i32::MAX, i32::MAX];
+        let total = sum(&s);
+        prusti_assert!(Int::new(total) == Int::new(i32::MAX as i64) + Int::new(i32::MAX as i64));
+    }
+
+    fn _test_mean() {
+        let s = [3, -1, 7];
+        let m = mean(&s);
+        prusti_assert!(-1 <= m && m <= 7);
+    }
+}
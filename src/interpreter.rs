@@ -0,0 +1,166 @@
+use prusti_contracts::*;
+
+// A tiny stack machine. The interpreter itself never checks for stack underflow/overflow
+// or an out-of-range program counter at runtime: Prusti proves that those cannot happen
+// as long as the program is valid (see `valid_program`). Values are not restricted, so
+// `Add` wraps around like two's complement hardware instead of panicking on overflow.
+
+pub const STACK_SIZE: usize = 16;
+
+#[derive(Clone, Copy)]
+pub enum Op {
+    Push(i32),
+    Add,
+    Dup,
+    Drop,
+}
+
+// Number of stack slots an operation needs to be present before it runs
+#[pure]
+fn required_depth(op: &Op) -> usize {
+    match op {
+        Op::Push(_) => 0,
+        Op::Add => 2,
+        Op::Dup => 1,
+        Op::Drop => 1,
+    }
+}
+
+// Stack depth after running `op` on a stack of depth `depth`
+#[pure]
+#[requires(required_depth(op) <= depth)]
+fn depth_after(op: &Op, depth: usize) -> usize {
+    match op {
+        Op::Push(_) => depth + 1,
+        Op::Add => depth - 1,
+        Op::Dup => depth + 1,
+        Op::Drop => depth - 1,
+    }
+}
+
+// Running `prog` from `pc` with a stack of depth `depth` never underflows
+// and never grows the stack past `STACK_SIZE`
+#[pure]
+#[requires(pc <= prog.len())]
+#[requires(depth <= STACK_SIZE)]
+#[cfg_attr(prusti, terminates(Int::new_usize(prog.len() - pc)))]
+fn valid_from(prog: &[Op], pc: usize, depth: usize) -> bool {
+    if pc == prog.len() {
+        true
+    } else {
+        let op = &prog[pc];
+        required_depth(op) <= depth
+            && depth_after(op, depth) <= STACK_SIZE
+            && valid_from(prog, pc + 1, depth_after(op, depth))
+    }
+}
+
+#[pure]
+pub fn valid_program(prog: &[Op]) -> bool {
+    valid_from(prog, 0, 0)
+}
+
+pub struct Machine {
+    stack: [i32; STACK_SIZE],
+    sp: usize,
+}
+
+impl Machine {
+    #[ensures(result.depth() == 0)]
+    pub fn new() -> Self {
+        Machine { stack: [0; STACK_SIZE], sp: 0 }
+    }
+
+    #[pure]
+    pub fn depth(&self) -> usize {
+        self.sp
+    }
+
+    #[pure]
+    #[requires(self.depth() > 0 && self.depth() <= STACK_SIZE)]
+    pub fn top(&self) -> i32 {
+        self.stack[self.sp - 1]
+    }
+
+    // Executes a single operation. The precondition is exactly what `valid_from` guarantees
+    // for the current instruction, so no runtime checks are necessary.
+    #[requires(self.depth() <= STACK_SIZE)]
+    #[requires(required_depth(&op) <= self.depth())]
+    #[requires(depth_after(&op, self.depth()) <= STACK_SIZE)]
+    #[ensures(self.depth() == depth_after(&op, old(self.depth())))]
+    #[ensures(matches!(op, Op::Add) ==>
+        self.top() == old(self.stack[self.sp - 2]).wrapping_add(old(self.stack[self.sp - 1])))]
+    fn step(&mut self, op: Op) {
+        match op {
+            Op::Push(value) => {
+                self.stack[self.sp] = value;
+                self.sp += 1;
+            }
+            Op::Add => {
+                let b = self.stack[self.sp - 1];
+                let a = self.stack[self.sp - 2];
+                self.stack[self.sp - 2] = a.wrapping_add(b);
+                self.sp -= 1;
+            }
+            Op::Dup => {
+                self.stack[self.sp] = self.stack[self.sp - 1];
+                self.sp += 1;
+            }
+            Op::Drop => {
+                self.sp -= 1;
+            }
+        }
+    }
+}
+
+// Runs at most `fuel` instructions of `prog`.
+// Returns the machine together with the program counter it stopped at.
+#[requires(valid_program(prog))]
+#[ensures(result.1 <= prog.len())]
+#[ensures(result.0.depth() <= STACK_SIZE)]
+#[ensures(valid_from(prog, result.1, result.0.depth()))]
+#[ensures(result.1 <= fuel)]
+pub fn run(prog: &[Op], fuel: usize) -> (Machine, usize) {
+    let mut machine = Machine::new();
+    let mut pc = 0;
+    let mut steps = 0;
+
+    while pc < prog.len() && steps < fuel {
+        body_invariant!(pc < prog.len());
+        body_invariant!(pc == steps && steps < fuel);
+        body_invariant!(machine.depth() <= STACK_SIZE);
+        body_invariant!(valid_from(prog, pc, machine.depth()));
+
+        machine.step(prog[pc]);
+        pc += 1;
+        steps += 1;
+    }
+
+    (machine, pc)
+}
+
+#[cfg(prusti)]
+mod prusti_tests {
+    use super::*;
+
+    fn _test_run() {
+        let prog = [Op::Push(1), Op::Push(2), Op::Add, Op::Dup, Op::Add];
+        prusti_assert!(valid_program(&prog));
+
+        let (machine, pc) = run(&prog, 10);
+        prusti_assert!(pc <= prog.len());
+        prusti_assert!(machine.depth() <= STACK_SIZE);
+    }
+
+    fn _test_fuel() {
+        let prog = [Op::Push(1), Op::Drop];
+        let (_, pc) = run(&prog, 1);
+        prusti_assert!(pc <= 1);
+    }
+
+    fn _test_invalid() {
+        let prog = [Op::Add];
+        // run(&prog, 1); // FAILS: `Add` needs two values on the stack
+        prusti_assert!(!valid_program(&prog));
+    }
+}
@@ -0,0 +1,226 @@
+use prusti_contracts::*;
+
+use crate::list_generic_with_peek::List;
+
+// Closed integer intervals `[lo, hi]`. The invariant rules out empty intervals,
+// so operations that could produce one (like `intersect`) return an `Option`.
+#[derive(Clone, Copy)]
+#[invariant(self.lo <= self.hi)]
+pub struct Interval {
+    lo: i32,
+    hi: i32,
+}
+
+impl Interval {
+    #[requires(lo <= hi)]
+    #[ensures(result.lo() == lo && result.hi() == hi)]
+    pub fn new(lo: i32, hi: i32) -> Self {
+        Interval { lo, hi }
+    }
+
+    #[pure]
+    #[ensures(result <= self.hi())]
+    pub fn lo(&self) -> i32 {
+        self.lo
+    }
+
+    #[pure]
+    pub fn hi(&self) -> i32 {
+        self.hi
+    }
+
+    #[pure]
+    #[ensures(result == (self.lo() <= x && x <= self.hi()))]
+    pub fn contains(&self, x: i32) -> bool {
+        self.lo <= x && x <= self.hi
+    }
+
+    // The two intervals have a point in common
+    #[pure]
+    #[ensures(result == (self.lo() <= other.hi() && other.lo() <= self.hi()))]
+    pub fn overlaps(&self, other: &Interval) -> bool {
+        self.lo <= other.hi && other.lo <= self.hi
+    }
+
+    #[ensures(match result {
+        Some(i) => forall(|x: i32| i.contains(x) == (self.contains(x) && other.contains(x))),
+        None => forall(|x: i32| !(self.contains(x) && other.contains(x))),
+    })]
+    pub fn intersect(&self, other: &Interval) -> Option<Interval> {
+        let lo = if self.lo > other.lo { self.lo } else { other.lo };
+        let hi = if self.hi < other.hi { self.hi } else { other.hi };
+        if lo <= hi {
+            Some(Interval::new(lo, hi))
+        } else {
+            None
+        }
+    }
+
+    // The two intervals overlap or are adjacent, like `[1, 3]` and `[4, 6]`.
+    // `hi + 1` is computed in `i64`, where it can't overflow.
+    #[pure]
+    #[ensures(result == (self.lo() as i64 <= other.hi() as i64 + 1 && other.lo() as i64 <= self.hi() as i64 + 1))]
+    pub fn touches(&self, other: &Interval) -> bool {
+        self.lo as i64 <= other.hi as i64 + 1 && other.lo as i64 <= self.hi as i64 + 1
+    }
+
+    // The union of two touching intervals is an interval again
+    #[requires(self.touches(other))]
+    #[ensures(forall(|x: i32| result.contains(x) == (self.contains(x) || other.contains(x))))]
+    pub fn merge(&self, other: &Interval) -> Interval {
+        let lo = if self.lo < other.lo { self.lo } else { other.lo };
+        let hi = if self.hi > other.hi { self.hi } else { other.hi };
+        Interval::new(lo, hi)
+    }
+}
+
+predicate! {
+    // Ascending, and there is at least one point missing between any two intervals
+    pub fn sorted_disjoint(list: &List<Interval>) -> bool {
+        forall(|i: usize, j: usize| (i < j && j < list.len()) ==>
+            list.lookup(i).hi() as i64 + 1 < list.lookup(j).lo() as i64)
+    }
+}
+
+predicate! {
+    pub fn in_list(list: &List<Interval>, x: i32) -> bool {
+        exists(|i: usize| i < list.len() && list.lookup(i).contains(x))
+    }
+}
+
+predicate! {
+    // `x` is in one of the first `k` intervals
+    fn in_prefix(list: &List<Interval>, k: usize, x: i32) -> bool {
+        exists(|i: usize| i < k && i < list.len() && list.lookup(i).contains(x))
+    }
+}
+
+// A set of integers, stored as the sorted list of its maximal intervals.
+// Clients only see the set through `contains`.
+#[invariant(sorted_disjoint(&self.intervals))]
+pub struct IntervalSet {
+    intervals: List<Interval>,
+}
+
+impl IntervalSet {
+    #[ensures(forall(|x: i32| !result.contains(x)))]
+    pub fn new() -> Self {
+        IntervalSet { intervals: List::new() }
+    }
+
+    // Number of intervals (not of points)
+    #[pure]
+    pub fn len(&self) -> usize {
+        self.intervals.len()
+    }
+
+    predicate! {
+        pub fn contains(&self, x: i32) -> bool {
+            in_list(&self.intervals, x)
+        }
+    }
+
+    // Stops at the first interval that starts behind `x`: by sortedness, so do all later ones
+    #[ensures(result == self.contains(x))]
+    pub fn contains_point(&self, x: i32) -> bool {
+        let mut i = 0;
+        while i < self.intervals.len() {
+            body_invariant!(i < self.intervals.len());
+            body_invariant!(forall(|j: usize| j < i ==> !self.intervals.lookup(j).contains(x)));
+
+            let interval = self.intervals.lookup(i);
+            if interval.contains(x) {
+                return true;
+            }
+            if interval.lo() > x {
+                return false;
+            }
+            i += 1;
+        }
+        false
+    }
+
+    // Goes through the intervals from the largest to the smallest and pushes them onto a new
+    // list, so it ends up in ascending order again. `current` collects everything that
+    // touches the new interval, until an interval with a gap below it shows where it goes.
+    #[ensures(forall(|x: i32| self.contains(x) == (old(self.contains(x)) || interval.contains(x))))]
+    pub fn insert(&mut self, interval: Interval) {
+        let mut result = List::new();
+        let mut current = interval;
+        let mut placed = false;
+        let mut k = self.intervals.len();
+
+        while k > 0 {
+            body_invariant!(k <= self.intervals.len());
+            body_invariant!(sorted_disjoint(&result));
+            // The intervals still to do are below everything in `result`
+            body_invariant!(result.len() > 0 ==> forall(|j: usize| j < k ==>
+                self.intervals.lookup(j).hi() as i64 + 1 < result.lookup(0).lo() as i64));
+            body_invariant!(!placed ==> (result.len() > 0 ==>
+                current.hi() as i64 + 1 < result.lookup(0).lo() as i64));
+            body_invariant!(placed ==> forall(|x: i32|
+                (in_list(&result, x) || in_prefix(&self.intervals, k, x))
+                == (old(self.contains(x)) || interval.contains(x))));
+            body_invariant!(!placed ==> forall(|x: i32|
+                (in_list(&result, x) || current.contains(x) || in_prefix(&self.intervals, k, x))
+                == (old(self.contains(x)) || interval.contains(x))));
+
+            k -= 1;
+            let next = *self.intervals.lookup(k);
+            if placed || next.lo() as i64 > current.hi() as i64 + 1 {
+                result.push(next);
+            } else if next.hi() as i64 + 1 < current.lo() as i64 {
+                result.push(current);
+                result.push(next);
+                placed = true;
+            } else {
+                current = current.merge(&next);
+            }
+        }
+        if !placed {
+            result.push(current);
+        }
+        self.intervals = result;
+    }
+}
+
+#[cfg(prusti)]
+mod prusti_tests {
+    use super::*;
+
+    fn _test_interval() {
+        let a = Interval::new(1, 5);
+        let b = Interval::new(4, 9);
+        prusti_assert!(a.overlaps(&b));
+
+        let both = a.intersect(&b);
+        prusti_assert!(match both {
+            Some(i) => i.contains(4) && i.contains(5) && !i.contains(6),
+            None => false,
+        });
+
+        let either = a.merge(&b);
+        prusti_assert!(either.contains(1) && either.contains(9) && !either.contains(10));
+
+        // Adjacent intervals merge as well
+        let d = Interval::new(6, 6);
+        let joined = a.merge(&d);
+        prusti_assert!(joined.contains(5) && joined.contains(6));
+
+        let c = Interval::new(7, 8);
+        // a.merge(&c); // FAILS: the union has a gap
+        let none = a.intersect(&c);
+        prusti_assert!(none.is_none());
+    }
+
+    fn _test_interval_set() {
+        let mut set = IntervalSet::new();
+        set.insert(Interval::new(1, 3));
+        set.insert(Interval::new(7, 9));
+        set.insert(Interval::new(2, 8));
+        let inside = set.contains_point(5);
+        prusti_assert!(inside);
+        let outside = set.contains_point(10);
+        prusti_assert!(!outside);
+    }
+}
\ No newline at end of file
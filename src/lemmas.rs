@@ -0,0 +1,155 @@
+use prusti_contracts::*;
+
+use crate::list_generic_with_peek::List;
+
+// Lemmas about lists, proven by induction over an index. None of them is trusted.
+//
+// A lemma is a pure function returning `true` whose postcondition is the fact it proves;
+// the body is the induction, one recursive call per step. Calling it inside a
+// `prusti_assert!` (or a spec) makes the fact available at that point:
+//
+//     prusti_assert!(lemma_count_perm(&list, &prev, i, j, &v));
+//
+// Counting goes through `count_prefix`, which only uses `lookup` and so works from outside
+// of the list module. (`List::count` follows the nodes instead.)
+
+// Number of elements equal to `value` among the first `n`
+#[pure]
+#[requires(n <= list.len())]
+#[cfg_attr(prusti, terminates(Int::new_usize(n)))]
+pub fn count_prefix<T: PartialEq>(list: &List<T>, value: &T, n: usize) -> usize {
+    if n == 0 {
+        0
+    } else if *list.lookup(n - 1) == *value {
+        count_prefix(list, value, n - 1) + 1
+    } else {
+        count_prefix(list, value, n - 1)
+    }
+}
+
+predicate! {
+    // two-state predicate: `list` is `prev` with one more element in front (what `push` does)
+    pub fn pushed<T>(list: &List<T>, prev: &List<T>) -> bool {
+        list.len() == prev.len() + 1
+        && forall(|i: usize| i < prev.len() ==> list.lookup(i + 1) === prev.lookup(i))
+    }
+}
+
+predicate! {
+    // `list` holds the elements of `a` followed by the elements of `b`
+    pub fn is_concat<T>(list: &List<T>, a: &List<T>, b: &List<T>) -> bool {
+        list.len() == a.len() + b.len()
+        && forall(|i: usize| i < a.len() ==> list.lookup(i) === a.lookup(i))
+        && forall(|i: usize| i < b.len() ==> list.lookup(a.len() + i) === b.lookup(i))
+    }
+}
+
+predicate! {
+    // two-state predicate: `list` is `prev` with the elements at `i` and `j` exchanged
+    pub fn swapped<T>(list: &List<T>, prev: &List<T>, i: usize, j: usize) -> bool {
+        list.len() == prev.len() && i < prev.len() && j < prev.len()
+        && list.lookup(i) === prev.lookup(j)
+        && list.lookup(j) === prev.lookup(i)
+        && forall(|k: usize| (k < prev.len() && k != i && k != j) ==> list.lookup(k) === prev.lookup(k))
+    }
+}
+
+// Pushing to the front doesn't move anything when counting from the back.
+// No induction needed: the quantifier of `pushed` only has to be instantiated at the right index.
+#[pure]
+#[requires(pushed(list, prev))]
+#[requires(k < prev.len())]
+#[ensures(list.lookup_from_back(k) === prev.lookup_from_back(k))]
+// The parameters only appear in the spec
+#[allow(unused_variables)]
+pub fn lemma_lookup_push<T>(list: &List<T>, prev: &List<T>, k: usize) -> bool {
+    true
+}
+
+// Up to the length of `a`, `list` counts like `a`
+#[pure]
+#[requires(is_concat(list, a, b))]
+#[requires(n <= a.len())]
+#[ensures(count_prefix(list, value, n) == count_prefix(a, value, n))]
+#[cfg_attr(prusti, terminates(Int::new_usize(n)))]
+#[allow(clippy::only_used_in_recursion)]
+fn lemma_count_prefix_concat<T: PartialEq>(list: &List<T>, a: &List<T>, b: &List<T>, value: &T, n: usize) -> bool {
+    n == 0 || lemma_count_prefix_concat(list, a, b, value, n - 1)
+}
+
+// The first `a.len() + n` elements of the concatenation count like all of `a` plus the
+// first `n` elements of `b`
+#[pure]
+#[requires(is_concat(list, a, b))]
+#[requires(n <= b.len())]
+#[ensures(count_prefix(list, value, a.len() + n) == count_prefix(a, value, a.len()) + count_prefix(b, value, n))]
+#[cfg_attr(prusti, terminates(Int::new_usize(n)))]
+#[allow(clippy::only_used_in_recursion)]
+fn lemma_count_concat_upto<T: PartialEq>(list: &List<T>, a: &List<T>, b: &List<T>, value: &T, n: usize) -> bool {
+    if n == 0 {
+        lemma_count_prefix_concat(list, a, b, value, a.len())
+    } else {
+        lemma_count_concat_upto(list, a, b, value, n - 1)
+    }
+}
+
+// Concatenation adds up the lengths and the counts
+#[pure]
+#[requires(is_concat(list, a, b))]
+#[ensures(list.len() == a.len() + b.len())]
+#[ensures(count_prefix(list, value, list.len()) == count_prefix(a, value, a.len()) + count_prefix(b, value, b.len()))]
+pub fn lemma_len_append<T: PartialEq>(list: &List<T>, a: &List<T>, b: &List<T>, value: &T) -> bool {
+    lemma_count_concat_upto(list, a, b, value, b.len())
+}
+
+// Induction over the prefix length. While the prefix contains neither `i` nor `j` the counts
+// agree; each of the two positions it passes adds the element of the other position. Written
+// with additions on both sides, so no step has to subtract.
+#[pure]
+#[requires(swapped(list, prev, i, j))]
+#[requires(n <= prev.len())]
+#[ensures(count_prefix(list, value, n)
+    + if i < n && *prev.lookup(i) == *value { 1 } else { 0 }
+    + if j < n && *prev.lookup(j) == *value { 1 } else { 0 }
+    == count_prefix(prev, value, n)
+    + if i < n && *prev.lookup(j) == *value { 1 } else { 0 }
+    + if j < n && *prev.lookup(i) == *value { 1 } else { 0 })]
+#[cfg_attr(prusti, terminates(Int::new_usize(n)))]
+#[allow(clippy::only_used_in_recursion)]
+fn lemma_count_swap_upto<T: PartialEq>(list: &List<T>, prev: &List<T>, i: usize, j: usize, value: &T, n: usize) -> bool {
+    n == 0 || lemma_count_swap_upto(list, prev, i, j, value, n - 1)
+}
+
+// Exchanging two elements doesn't change how often a value occurs
+#[pure]
+#[requires(swapped(list, prev, i, j))]
+#[ensures(count_prefix(list, value, list.len()) == count_prefix(prev, value, prev.len()))]
+pub fn lemma_count_perm<T: PartialEq>(list: &List<T>, prev: &List<T>, i: usize, j: usize, value: &T) -> bool {
+    lemma_count_swap_upto(list, prev, i, j, value, prev.len())
+}
+
+#[cfg(prusti)]
+mod prusti_tests {
+    use super::*;
+
+    fn _test_lookup_push() {
+        let mut list = List::new();
+        list.push(1);
+        list.push(2);
+        let prev = snap(&list);
+        list.push(3);
+        prusti_assert!(lemma_lookup_push(&list, &prev, 0));
+        prusti_assert!(*list.lookup_from_back(0) == 1);
+    }
+
+    fn _test_count_perm() {
+        let mut a = List::new();
+        a.push(2);
+        a.push(1);
+        let mut b = List::new();
+        b.push(1);
+        b.push(2);
+        prusti_assert!(lemma_count_perm(&b, &a, 0, 1, &1));
+        prusti_assert!(count_prefix(&b, &1, 2) == count_prefix(&a, &1, 2));
+    }
+}
\ No newline at end of file
@@ -35,6 +35,8 @@ enum Link {
 
 impl Link {
     #[pure]
+    // Structural recursion: every call descends into a strictly smaller `Box`
+    #[cfg_attr(prusti, terminates(trusted))]
     fn len(&self) -> usize {
         match self {
             Link::Empty => 0,
@@ -49,6 +51,7 @@ impl Link {
 
     #[pure]
     #[requires(index < self.len())]
+    #[cfg_attr(prusti, terminates(Int::new_usize(index)))]
     pub fn lookup(&self, index: usize) -> i32 {
         match self {
             Link::More(node) => {
@@ -183,9 +186,4 @@ mod prusti_tests {
         prusti_assert!(list.is_empty() && list.len() == 0);
         prusti_assert!(z.is_none());
     }
-}
-
-#[trusted]
-fn print(s: &str) {
-    println!("{s}");
 }
\ No newline at end of file
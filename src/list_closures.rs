@@ -0,0 +1,173 @@
+use prusti_contracts::*;
+
+use crate::list_generic_with_peek::List;
+
+// Specs can't call a closure, so the functions below take the closure together with a
+// `Property` that describes it. The entailment
+//     f |= |elem: &T| [requires(true), ensures(cl_result == property.holds(elem))]
+// says that `f` decides exactly the property, and the postconditions talk about the property.
+pub trait Property<T> {
+    #[pure]
+    fn holds(&self, elem: &T) -> bool;
+}
+
+// Number of the first `n` elements that have the property
+#[pure]
+#[requires(n <= list.len())]
+#[ensures(result <= n)]
+#[cfg_attr(prusti, terminates(Int::new_usize(n)))]
+pub fn count_holds<T, P: Property<T>>(list: &List<T>, property: &P, n: usize) -> usize {
+    if n == 0 {
+        0
+    } else {
+        count_holds(list, property, n - 1) + if property.holds(list.lookup(n - 1)) { 1 } else { 0 }
+    }
+}
+
+impl<T> List<T> {
+    // Stable partition: the elements with the property go to the first list, the others to
+    // the second, both in their original order. Of the first `i` elements, `count_holds(i)`
+    // went to the first list, so that's where element `i` ends up in its list.
+    #[requires(f |= |elem: &T| [requires(true), ensures(cl_result == property.holds(elem))])]
+    #[ensures(result.0.len() + result.1.len() == old(self.len()))]
+    #[ensures(result.0.len() == old(count_holds(&self, property, self.len())))]
+    #[ensures(forall(|i: usize| i < result.0.len() ==> property.holds(result.0.lookup(i))))]
+    #[ensures(forall(|i: usize| i < result.1.len() ==> !property.holds(result.1.lookup(i))))]
+    #[ensures(forall(|i: usize| (i < old(self.len()) && property.holds(old(self.lookup(i)))) ==>
+        result.0.lookup(old(count_holds(&self, property, i))) === old(self.lookup(i))))]
+    #[ensures(forall(|i: usize| (i < old(self.len()) && !property.holds(old(self.lookup(i)))) ==>
+        result.1.lookup(i - old(count_holds(&self, property, i))) === old(self.lookup(i))))]
+    // `property` only appears in the specs
+    #[allow(unused_variables)]
+    pub fn partition<F: Fn(&T) -> bool, P: Property<T>>(mut self, f: F, property: &P) -> (List<T>, List<T>) {
+        #[cfg(prusti)]
+        let original = ghost!(snap(&self));
+
+        let mut yes = List::new();
+        let mut no = List::new();
+        while !self.is_empty() {
+            // `yes.len() + no.len()` elements were moved so far
+            body_invariant!(yes.len() + no.len() + self.len() == original.len());
+            body_invariant!(forall(|k: usize| k < self.len() ==>
+                self.lookup(k) === original.lookup(k + yes.len() + no.len())));
+            body_invariant!(yes.len() == count_holds(&original, property, yes.len() + no.len()));
+            body_invariant!(forall(|i: usize| i < yes.len() ==> property.holds(yes.lookup(i))));
+            body_invariant!(forall(|i: usize| i < no.len() ==> !property.holds(no.lookup(i))));
+            body_invariant!(forall(|i: usize| (i < yes.len() + no.len() && property.holds(original.lookup(i))) ==>
+                yes.lookup(count_holds(&original, property, i)) === original.lookup(i)));
+            body_invariant!(forall(|i: usize| (i < yes.len() + no.len() && !property.holds(original.lookup(i))) ==>
+                no.lookup(i - count_holds(&original, property, i)) === original.lookup(i)));
+
+            let elem = self.pop();
+            if f(&elem) {
+                yes.push_back(elem);
+            } else {
+                no.push_back(elem);
+            }
+        }
+        (yes, no)
+    }
+
+    #[requires(f |= |elem: &T| [requires(true), ensures(cl_result == property.holds(elem))])]
+    #[ensures(result == count_holds(self, property, self.len()))]
+    #[allow(unused_variables)]
+    pub fn count_if<F: Fn(&T) -> bool, P: Property<T>>(&self, f: F, property: &P) -> usize {
+        let mut count = 0;
+        let mut i = 0;
+        while i < self.len() {
+            body_invariant!(i < self.len());
+            body_invariant!(count == count_holds(self, property, i));
+
+            if f(self.lookup(i)) {
+                count += 1;
+            }
+            i += 1;
+        }
+        count
+    }
+
+    // Stops at the first element with the property
+    #[requires(f |= |elem: &T| [requires(true), ensures(cl_result == property.holds(elem))])]
+    #[ensures(result == exists(|i: usize| i < self.len() && property.holds(self.lookup(i))))]
+    #[allow(unused_variables)]
+    pub fn any<F: Fn(&T) -> bool, P: Property<T>>(&self, f: F, property: &P) -> bool {
+        let mut i = 0;
+        while i < self.len() {
+            body_invariant!(i < self.len());
+            body_invariant!(forall(|j: usize| j < i ==> !property.holds(self.lookup(j))));
+
+            if f(self.lookup(i)) {
+                return true;
+            }
+            i += 1;
+        }
+        false
+    }
+
+    // Stops at the first element without the property
+    #[requires(f |= |elem: &T| [requires(true), ensures(cl_result == property.holds(elem))])]
+    #[ensures(result == forall(|i: usize| i < self.len() ==> property.holds(self.lookup(i))))]
+    #[allow(unused_variables)]
+    pub fn all<F: Fn(&T) -> bool, P: Property<T>>(&self, f: F, property: &P) -> bool {
+        let mut i = 0;
+        while i < self.len() {
+            body_invariant!(i < self.len());
+            body_invariant!(forall(|j: usize| j < i ==> property.holds(self.lookup(j))));
+
+            if !f(self.lookup(i)) {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+}
+
+#[cfg(prusti)]
+mod prusti_tests {
+    use super::*;
+
+    struct IsEven;
+
+    impl Property<i32> for IsEven {
+        #[pure]
+        fn holds(&self, elem: &i32) -> bool {
+            *elem % 2 == 0
+        }
+    }
+
+    fn _test_partition() {
+        let mut list = List::new();
+        list.push(4);
+        list.push(3);
+        list.push(2);
+        list.push(1);
+        // The list is [1, 2, 3, 4]
+        let is_even = closure!(
+            ensures(result == (*elem % 2 == 0)),
+            |elem: &i32| -> bool { *elem % 2 == 0 }
+        );
+        let (even, odd) = list.partition(is_even, &IsEven);
+        prusti_assert!(even.len() + odd.len() == 4);
+        prusti_assert!(*even.lookup(0) == 2 && *even.lookup(1) == 4);
+        prusti_assert!(*odd.lookup(0) == 1 && *odd.lookup(1) == 3);
+    }
+
+    fn _test_count_any_all() {
+        let mut list = List::new();
+        list.push(4);
+        list.push(3);
+        list.push(2);
+        // The list is [2, 3, 4]
+        let is_even = closure!(
+            ensures(result == (*elem % 2 == 0)),
+            |elem: &i32| -> bool { *elem % 2 == 0 }
+        );
+        let n = list.count_if(&is_even, &IsEven);
+        prusti_assert!(n == 2);
+        let some = list.any(&is_even, &IsEven);
+        prusti_assert!(some);
+        let every = list.all(&is_even, &IsEven);
+        prusti_assert!(!every);
+    }
+}
\ No newline at end of file
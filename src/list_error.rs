@@ -0,0 +1,102 @@
+use prusti_contracts::*;
+
+use crate::list_generic_with_peek::{CapacityError, List};
+
+// The errors of the checked list operations, with the values that explain them.
+//
+// An enum can't have a type invariant, so what the payloads must satisfy is the pure
+// `is_valid` instead. The constructor functions require it and the checked operations
+// promise it, so a client that gets an error also knows why it happened (e.g. that the
+// index really was out of bounds).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ListError {
+    IndexOutOfBounds { index: usize, len: usize },
+    Empty,
+    Full { cap: usize },
+}
+
+impl ListError {
+    #[pure]
+    pub fn is_valid(&self) -> bool {
+        match self {
+            ListError::IndexOutOfBounds { index, len } => *index >= *len,
+            ListError::Empty => true,
+            ListError::Full { .. } => true,
+        }
+    }
+
+    #[requires(index >= len)]
+    #[ensures(result.is_valid())]
+    #[ensures(result === ListError::IndexOutOfBounds { index, len })]
+    pub fn index_out_of_bounds(index: usize, len: usize) -> Self {
+        ListError::IndexOutOfBounds { index, len }
+    }
+
+    #[ensures(result.is_valid())]
+    #[ensures(result === ListError::Full { cap })]
+    pub fn full(cap: usize) -> Self {
+        ListError::Full { cap }
+    }
+}
+
+#[refine_trait_spec]
+impl From<CapacityError> for ListError {
+    #[ensures(result.is_valid())]
+    #[ensures(result === ListError::Full { cap: err.cap })]
+    fn from(err: CapacityError) -> Self {
+        ListError::full(err.cap)
+    }
+}
+
+impl<T> List<T> {
+    // `pop` that reports an empty list instead of requiring a non-empty one
+    #[ensures(match result {
+        Ok(_) => self.len() == old(self.len()) - 1,
+        Err(err) => err === ListError::Empty && old(self.is_empty()),
+    })]
+    #[ensures(result.is_ok() ==> result === Ok(old(snap(self.lookup(0)))))]
+    #[ensures(result.is_err() ==> snap(self) === old(snap(self)))]
+    pub fn pop_checked(&mut self) -> Result<T, ListError> {
+        if self.is_empty() {
+            return Err(ListError::Empty);
+        }
+        Ok(self.pop())
+    }
+
+    // `remove_at` that reports a bad index. The error carries the index and the length.
+    #[ensures(match result {
+        Ok(_) => index < old(self.len()) && self.len() == old(self.len()) - 1,
+        Err(err) => err.is_valid() && err === ListError::IndexOutOfBounds { index, len: old(self.len()) },
+    })]
+    #[ensures(result.is_ok() ==> result === Ok(old(snap(self.lookup(index)))))]
+    #[ensures(result.is_err() ==> snap(self) === old(snap(self)))]
+    pub fn remove_at_checked(&mut self, index: usize) -> Result<T, ListError> {
+        if index >= self.len() {
+            return Err(ListError::index_out_of_bounds(index, self.len()));
+        }
+        Ok(self.remove_at(index))
+    }
+}
+
+#[cfg(prusti)]
+mod prusti_tests {
+    use super::*;
+
+    fn _test_list_error() {
+        let mut list = List::new();
+        let empty = list.pop_checked();
+        prusti_assert!(empty === Err(ListError::Empty));
+
+        list.push(1);
+        let bad = list.remove_at_checked(3);
+        prusti_assert!(bad === Err(ListError::IndexOutOfBounds { index: 3, len: 1 }));
+        let good = list.remove_at_checked(0);
+        prusti_assert!(good === Ok(1) && list.is_empty());
+
+        if let Err(err) = list.push_checked(2, 0) {
+            let converted = ListError::from(err);
+            prusti_assert!(converted === ListError::Full { cap: 0 });
+        }
+        // ListError::index_out_of_bounds(0, 1); // FAILS: the index is in bounds
+    }
+}
\ No newline at end of file
@@ -0,0 +1,223 @@
+use prusti_contracts::*;
+
+use crate::list_generic_with_peek::List;
+
+// Iterators over a `List`, and adapters on top of them.
+//
+// The iterators own what they still have to yield, so `next` is just a `try_pop`.
+// Every iterator describes its upcoming items with `remaining` and `upcoming(i)`,
+// the same way a list is described with `len` and `lookup`. The specs of `next` then say
+// that the first upcoming item is yielded and the others move forward by one.
+
+#[pure]
+fn min(a: usize, b: usize) -> usize {
+    if a <= b {
+        a
+    } else {
+        b
+    }
+}
+
+pub struct IntoIter<T> {
+    list: List<T>,
+}
+
+#[refine_trait_spec]
+impl<T> IntoIterator for List<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    #[ensures(result.remaining() == self.len())]
+    #[ensures(forall(|i: usize| i < self.len() ==> result.upcoming(i) === self.lookup(i)))]
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter { list: self }
+    }
+}
+
+impl<T> IntoIter<T> {
+    #[pure]
+    pub fn remaining(&self) -> usize {
+        self.list.len()
+    }
+
+    #[pure]
+    #[requires(i < self.remaining())]
+    pub fn upcoming(&self, i: usize) -> &T {
+        self.list.lookup(i)
+    }
+
+    // Yields the first `n` items, or all of them if there are fewer.
+    // (Shadows `Iterator::take`, whose result Prusti knows nothing about.)
+    #[ensures(result.remaining() == min(n, self.remaining()))]
+    #[ensures(forall(|i: usize| i < result.remaining() ==> result.upcoming(i) === self.upcoming(i)))]
+    pub fn take(self, n: usize) -> Take<T> {
+        Take { iter: self, n }
+    }
+
+    // Leaves out the first `n` items. The `i`-th item yielded is the `(i + n)`-th one of `self`.
+    // (Shadows `Iterator::skip`, like `take`.)
+    #[ensures(self.remaining() >= n ==> result.remaining() == self.remaining() - n)]
+    #[ensures(self.remaining() < n ==> result.remaining() == 0)]
+    #[ensures(forall(|i: usize| i < result.remaining() ==> result.upcoming(i) === self.upcoming(i + n)))]
+    pub fn skip(self, n: usize) -> Skip<T> {
+        Skip { iter: self, n }
+    }
+}
+
+#[refine_trait_spec]
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    #[ensures(old(self.remaining()) == 0 ==> result.is_none() && self.remaining() == 0)]
+    #[ensures(old(self.remaining()) > 0 ==>
+        self.remaining() == old(self.remaining()) - 1
+        && result === Some(old(snap(self.upcoming(0))))
+        && forall(|i: usize| i < self.remaining() ==> self.upcoming(i) === old(self.upcoming(i + 1))))]
+    fn next(&mut self) -> Option<T> {
+        self.list.try_pop()
+    }
+}
+
+// At most `n` more items come out, even if the underlying iterator has more
+pub struct Take<T> {
+    iter: IntoIter<T>,
+    n: usize,
+}
+
+impl<T> Take<T> {
+    #[pure]
+    pub fn remaining(&self) -> usize {
+        min(self.n, self.iter.remaining())
+    }
+
+    #[pure]
+    #[requires(i < self.remaining())]
+    pub fn upcoming(&self, i: usize) -> &T {
+        self.iter.upcoming(i)
+    }
+}
+
+#[refine_trait_spec]
+impl<T> Iterator for Take<T> {
+    type Item = T;
+
+    #[ensures(old(self.remaining()) == 0 ==> result.is_none() && self.remaining() == 0)]
+    #[ensures(old(self.remaining()) > 0 ==>
+        self.remaining() == old(self.remaining()) - 1
+        && result === Some(old(snap(self.upcoming(0))))
+        && forall(|i: usize| i < self.remaining() ==> self.upcoming(i) === old(self.upcoming(i + 1))))]
+    fn next(&mut self) -> Option<T> {
+        if self.n == 0 {
+            None
+        } else {
+            self.n -= 1;
+            self.iter.next()
+        }
+    }
+}
+
+// The first `n` items are only dropped on the first call to `next`
+pub struct Skip<T> {
+    iter: IntoIter<T>,
+    n: usize,
+}
+
+impl<T> Skip<T> {
+    // Nothing is left if there are fewer than `n` items
+    #[pure]
+    pub fn remaining(&self) -> usize {
+        if self.iter.remaining() >= self.n {
+            self.iter.remaining() - self.n
+        } else {
+            0
+        }
+    }
+
+    // `i < remaining()` is what keeps `i + n` in bounds
+    #[pure]
+    #[requires(i < self.remaining())]
+    pub fn upcoming(&self, i: usize) -> &T {
+        self.iter.upcoming(i + self.n)
+    }
+}
+
+#[refine_trait_spec]
+impl<T> Iterator for Skip<T> {
+    type Item = T;
+
+    #[ensures(old(self.remaining()) == 0 ==> result.is_none() && self.remaining() == 0)]
+    #[ensures(old(self.remaining()) > 0 ==>
+        self.remaining() == old(self.remaining()) - 1
+        && result === Some(old(snap(self.upcoming(0))))
+        && forall(|i: usize| i < self.remaining() ==> self.upcoming(i) === old(self.upcoming(i + 1))))]
+    fn next(&mut self) -> Option<T> {
+        // Dropping one of the skipped items changes nothing that is visible from outside
+        while self.n > 0 {
+            body_invariant!(self.n > 0);
+            body_invariant!(self.remaining() == old(self.remaining()));
+            body_invariant!(forall(|i: usize| i < self.remaining() ==>
+                self.upcoming(i) === old(self.upcoming(i))));
+
+            self.iter.next();
+            self.n -= 1;
+        }
+        self.iter.next()
+    }
+}
+
+#[cfg(prusti)]
+mod prusti_tests {
+    use super::*;
+
+    fn _test_into_iter() {
+        let mut list = List::new();
+        list.push(2);
+        list.push(1);
+        let mut iter = list.into_iter();
+        let first = iter.next();
+        prusti_assert!(first === Some(1));
+        let second = iter.next();
+        prusti_assert!(second === Some(2));
+        let done = iter.next();
+        prusti_assert!(done.is_none());
+    }
+
+    fn _test_take() {
+        let mut list = List::new();
+        list.push(3);
+        list.push(2);
+        list.push(1);
+        let mut first_two = list.into_iter().take(2);
+        prusti_assert!(first_two.remaining() == 2);
+        let a = first_two.next();
+        let b = first_two.next();
+        let c = first_two.next();
+        prusti_assert!(a === Some(1) && b === Some(2));
+        // The third item is still there, but `take` doesn't yield it
+        prusti_assert!(c.is_none());
+
+        let mut short = List::new();
+        short.push(1);
+        let all = short.into_iter().take(5);
+        prusti_assert!(all.remaining() == 1);
+    }
+
+    fn _test_skip() {
+        let mut list = List::new();
+        list.push(3);
+        list.push(2);
+        list.push(1);
+        let mut rest = list.into_iter().skip(1);
+        prusti_assert!(rest.remaining() == 2);
+        let a = rest.next();
+        let b = rest.next();
+        let c = rest.next();
+        prusti_assert!(a === Some(2) && b === Some(3));
+        prusti_assert!(c.is_none());
+
+        let mut short = List::new();
+        short.push(1);
+        let nothing = short.into_iter().skip(5);
+        prusti_assert!(nothing.remaining() == 0);
+    }
+}
\ No newline at end of file
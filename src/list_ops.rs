@@ -0,0 +1,225 @@
+use prusti_contracts::*;
+
+use crate::list_generic_with_peek::List;
+// Predicates only exist when verifying
+#[cfg(prusti)]
+use crate::lemmas::is_concat;
+
+impl<T: PartialEq> List<T> {
+    // The first occurrence of `v`: nothing before the result matches, otherwise the
+    // loop would have stopped there
+    #[ensures(match result {
+        Some(index) => index < self.len() && *self.lookup(index) == *v
+            && forall(|j: usize| j < index ==> !(*self.lookup(j) == *v)),
+        None => forall(|j: usize| j < self.len() ==> !(*self.lookup(j) == *v)),
+    })]
+    pub fn find(&self, v: &T) -> Option<usize> {
+        let mut i = 0;
+        while i < self.len() {
+            body_invariant!(i < self.len());
+            body_invariant!(forall(|j: usize| j < i ==> !(*self.lookup(j) == *v)));
+
+            if *self.lookup(i) == *v {
+                return Some(i);
+            }
+            i += 1;
+        }
+        None
+    }
+
+    // Removes every element equal to the one in front of it. Doesn't need a sorted list,
+    // but then equal elements that aren't neighbours stay. What's left is a subsequence:
+    // any two remaining elements were found in the old list in the same order. The mapping
+    // uses `===`, since a `PartialEq` needn't be reflexive (`NaN != NaN`).
+    #[ensures(self.len() <= old(self.len()))]
+    #[ensures(old(self.len()) > 0 ==> self.len() > 0)]
+    #[ensures(forall(|i: usize| i + 1 < self.len() ==> !(*self.lookup(i) == *self.lookup(i + 1))))]
+    #[ensures(forall(|i: usize| i < self.len() ==>
+        exists(|p: usize| p < old(self.len()) && self.lookup(i) === old(self.lookup(p)))))]
+    #[ensures(forall(|i: usize, j: usize| (i < j && j < self.len()) ==>
+        exists(|p: usize, q: usize| p < q && q < old(self.len())
+            && self.lookup(i) === old(self.lookup(p)) && self.lookup(j) === old(self.lookup(q)))))]
+    pub fn dedup_consecutive(&mut self) {
+        if self.len() <= 1 {
+            return;
+        }
+        let head = self.pop();
+        self.dedup_consecutive();
+        // The rest isn't empty. `head` is compared in the same order as the postcondition
+        // compares neighbours, `==` doesn't have to be symmetric either.
+        if !(head == *self.lookup(0)) {
+            self.push(head);
+        }
+    }
+}
+
+impl List<i32> {
+    // The first position of the smallest element. Only a strictly smaller element
+    // moves `min`, so it stays at the first occurrence.
+    #[requires(!self.is_empty())]
+    #[ensures(result < self.len())]
+    #[ensures(forall(|i: usize| i < self.len() ==> *self.lookup(result) <= *self.lookup(i)))]
+    #[ensures(forall(|i: usize| i < result ==> *self.lookup(i) > *self.lookup(result)))]
+    pub fn position_of_min(&self) -> usize {
+        let mut min = 0;
+        let mut i = 1;
+        while i < self.len() {
+            body_invariant!(min < i && i < self.len());
+            body_invariant!(forall(|j: usize| j < i ==> *self.lookup(min) <= *self.lookup(j)));
+            body_invariant!(forall(|j: usize| j < min ==> *self.lookup(j) > *self.lookup(min)));
+
+            if *self.lookup(i) < *self.lookup(min) {
+                min = i;
+            }
+            i += 1;
+        }
+        min
+    }
+}
+
+impl<T> List<T> {
+    // Cuts the list into pieces of `k` elements; only the last one may be shorter.
+    // Element `j` of chunk `c` was element `c * k + j`.
+    #[requires(k > 0)]
+    #[ensures(result.len() == old(self.len()) / k + if old(self.len()) % k == 0 { 0 } else { 1 })]
+    #[ensures(forall(|c: usize| c + 1 < result.len() ==> result.lookup(c).len() == k))]
+    #[ensures(result.len() > 0 ==>
+        result.lookup(result.len() - 1).len() == old(self.len()) - (result.len() - 1) * k)]
+    #[ensures(forall(|c: usize, j: usize| (c < result.len() && j < result.lookup(c).len()) ==>
+        result.lookup(c).lookup(j) === old(self.lookup(c * k + j))))]
+    pub fn chunks(mut self, k: usize) -> List<List<T>> {
+        if self.is_empty() {
+            return List::new();
+        }
+        let first = if self.len() < k { self.len() } else { k };
+        let rest = self.split_off(first);
+        let mut result = rest.chunks(k);
+        result.push(self);
+        result
+    }
+}
+
+impl<T> List<List<T>> {
+    // Concatenates the inner lists. Element `j` of list `c` comes after all elements of
+    // the lists before it, which are `flat_len(c)` many.
+    #[ensures(result.len() == old(flat_len(&self, self.len())))]
+    #[ensures(forall(|c: usize, j: usize| (c < old(self.len()) && j < old(self.lookup(c).len())) ==>
+        result.lookup(old(flat_len(&self, c)) + j) === old(self.lookup(c).lookup(j))))]
+    pub fn flatten(mut self) -> List<T> {
+        if self.is_empty() {
+            return List::new();
+        }
+        #[cfg(prusti)]
+        let lists = ghost!(snap(&self));
+
+        let first = self.pop();
+        prusti_assert!(forall(|c: usize| c <= self.len() ==> lemma_flat_len_tail(&lists, &self, c)));
+        let rest = self.flatten();
+        prepend(first, rest)
+    }
+}
+
+// Total length of the first `c` inner lists
+#[pure]
+#[requires(c <= lists.len())]
+#[cfg_attr(prusti, terminates(Int::new_usize(c)))]
+pub fn flat_len<T>(lists: &List<List<T>>, c: usize) -> usize {
+    if c == 0 {
+        0
+    } else {
+        flat_len(lists, c - 1) + lists.lookup(c - 1).len()
+    }
+}
+
+// Lemma: without the first list, every prefix is shorter by the length of that list
+#[pure]
+#[requires(tail.len() + 1 == lists.len() && c < lists.len())]
+#[requires(forall(|i: usize| i < tail.len() ==> tail.lookup(i) === lists.lookup(i + 1)))]
+#[ensures(flat_len(lists, c + 1) == lists.lookup(0).len() + flat_len(tail, c))]
+#[cfg_attr(prusti, terminates(Int::new_usize(c)))]
+#[allow(clippy::only_used_in_recursion)]
+fn lemma_flat_len_tail<T>(lists: &List<List<T>>, tail: &List<List<T>>, c: usize) -> bool {
+    c == 0 || lemma_flat_len_tail(lists, tail, c - 1)
+}
+
+// `front` followed by `back`
+#[ensures(is_concat(&result, &old(snap(&front)), &old(snap(&back))))]
+fn prepend<T>(mut front: List<T>, back: List<T>) -> List<T> {
+    if front.is_empty() {
+        return back;
+    }
+    let head = front.pop();
+    let mut result = prepend(front, back);
+    result.push(head);
+    result
+}
+
+#[cfg(prusti)]
+mod prusti_tests {
+    use super::*;
+
+    fn _test_find() {
+        let mut list = List::new();
+        list.push(5);
+        list.push(7);
+        list.push(5);
+        // The list is [5, 7, 5]
+        let first = list.find(&5);
+        prusti_assert!(first === Some(0));
+        let seven = list.find(&7);
+        prusti_assert!(seven === Some(1));
+        let missing = list.find(&9);
+        prusti_assert!(missing.is_none());
+    }
+
+    fn _test_position_of_min() {
+        let mut list = List::new();
+        list.push(1);
+        list.push(4);
+        list.push(1);
+        list.push(6);
+        // The list is [6, 1, 4, 1]
+        let pos = list.position_of_min();
+        prusti_assert!(pos == 1);
+    }
+
+    fn _test_dedup_consecutive() {
+        let mut list = List::new();
+        list.push(1);
+        list.push(2);
+        list.push(2);
+        list.push(1);
+        list.push(1);
+        // The list is [1, 1, 2, 2, 1]
+        list.dedup_consecutive();
+        prusti_assert!(list.len() > 0 && list.len() <= 5);
+        prusti_assert!(forall(|i: usize| i + 1 < list.len() ==> list[i] != list[i + 1]));
+    }
+
+    fn _test_chunks() {
+        let mut list = List::new();
+        list.push(5);
+        list.push(4);
+        list.push(3);
+        list.push(2);
+        list.push(1);
+        // The list is [1, 2, 3, 4, 5]
+        let chunks = list.chunks(2);
+        prusti_assert!(chunks.len() == 3);
+        prusti_assert!(chunks.lookup(0).len() == 2 && chunks.lookup(2).len() == 1);
+        prusti_assert!(*chunks.lookup(1).lookup(0) == 3);
+        prusti_assert!(*chunks.lookup(2).lookup(0) == 5);
+    }
+
+    fn _test_flatten() {
+        let mut list = List::new();
+        list.push(3);
+        list.push(2);
+        list.push(1);
+        // The list is [1, 2, 3]
+        let chunks = list.chunks(2);
+        let flat = chunks.flatten();
+        prusti_assert!(flat.len() == 3);
+        prusti_assert!(flat[0] == 1 && flat[1] == 2 && flat[2] == 3);
+    }
+}
\ No newline at end of file
@@ -35,6 +35,8 @@ struct Node {
 type Link = Option<Box<Node>>;
 
 #[pure]
+// Structural recursion: every call descends into a strictly smaller `Box`
+#[cfg_attr(prusti, terminates(trusted))]
 fn link_len(link: &Link) -> usize {
     match link {
         None => 0,
@@ -44,6 +46,7 @@ fn link_len(link: &Link) -> usize {
 
 #[pure]
 #[requires(index < link_len(link))]
+#[cfg_attr(prusti, terminates(Int::new_usize(index)))]
 fn link_lookup(link: &Link, index: usize) -> i32 {
     match link {
         Some(node) => {
@@ -171,10 +174,4 @@ mod prusti_tests {
         prusti_assert!(list.is_empty() && list.len() == 0);
         prusti_assert!(z.is_none());
     }
-}
-
-
-#[trusted]
-fn print(s: &str) {
-    println!("{s}");
 }
\ No newline at end of file
@@ -0,0 +1,66 @@
+use prusti_contracts::*;
+use std::fmt::Debug;
+
+use crate::list_generic_with_peek::List;
+
+// Only the two I/O primitives are trusted: Prusti can't look into `println!` or `Debug`
+// formatting. Everything else is verified, in particular that logging never changes
+// the values it logs (snapshot equality with the old value).
+
+#[trusted]
+pub fn print(s: &str) {
+    println!("{s}");
+}
+
+#[trusted]
+#[ensures(snap(value) === old(snap(value)))]
+fn print_debug<T: Debug>(value: &T) {
+    println!("{value:?}");
+}
+
+#[ensures(snap(value) === old(snap(value)))]
+pub fn log_value<T: Debug>(label: &str, value: &T) {
+    print(label);
+    print_debug(value);
+}
+
+#[ensures(snap(list) === old(snap(list)))]
+#[ensures(list.len() == old(list.len()))]
+pub fn log_list(label: &str, list: &List<i32>) {
+    print(label);
+    let mut i = 0;
+    while i < list.len() {
+        body_invariant!(i < list.len());
+        print_debug(list.lookup(i));
+        i += 1;
+    }
+}
+
+#[ensures(snap(values) === old(snap(values)))]
+pub fn log_slice<T: Debug>(label: &str, values: &[T]) {
+    print(label);
+    let mut i = 0;
+    while i < values.len() {
+        body_invariant!(i < values.len());
+        print_debug(&values[i]);
+        i += 1;
+    }
+}
+
+#[cfg(prusti)]
+mod prusti_tests {
+    use super::*;
+
+    fn _test_logging() {
+        let mut list = List::new();
+        list.push(5);
+        list.push(10);
+
+        log_list("list:", &list);
+        log_value("head:", list.lookup(0));
+
+        // Logging doesn't change anything
+        prusti_assert!(list.len() == 2);
+        prusti_assert!(*list.lookup(0) == 10 && *list.lookup(1) == 5);
+    }
+}
@@ -0,0 +1,160 @@
+use prusti_contracts::*;
+
+// Loops need a `body_invariant!`: a property that holds at the start of every iteration.
+// Prusti forgets everything about the variables that the loop modifies, except what the
+// invariant (and the loop condition) says. A good invariant usually says
+// "the part of the input processed so far already has the property the postcondition wants".
+
+// 1. Summation: the accumulator equals the (recursively specified) sum of the prefix
+#[pure]
+#[requires(k <= s.len())]
+#[cfg_attr(prusti, terminates(Int::new_usize(k)))]
+pub fn sum_upto(s: &[u32], k: usize) -> u64 {
+    if k == 0 {
+        0
+    } else {
+        sum_upto(s, k - 1) + s[k - 1] as u64
+    }
+}
+
+#[requires(s.len() <= u32::MAX as usize)]
+#[ensures(result == sum_upto(s, s.len()))]
+pub fn sum(s: &[u32]) -> u64 {
+    let mut acc = 0;
+    let mut i = 0;
+    while i < s.len() {
+        body_invariant!(i < s.len());
+        body_invariant!(acc == sum_upto(s, i));
+        // Bounds the accumulator, so the assertion below can show that it can't overflow
+        // (`Prusti.toml` turns off overflow checks)
+        body_invariant!(acc <= i as u64 * u32::MAX as u64);
+
+        prusti_assert!(acc as u128 + s[i] as u128 <= u64::MAX as u128);
+        acc += s[i] as u64;
+        i += 1;
+    }
+    acc
+}
+
+// 2. Counting: the counter equals the number of matches in the prefix
+#[pure]
+#[requires(k <= s.len())]
+#[ensures(result <= k)]
+#[cfg_attr(prusti, terminates(Int::new_usize(k)))]
+pub fn count_upto(s: &[i32], value: i32, k: usize) -> usize {
+    if k == 0 {
+        0
+    } else if s[k - 1] == value {
+        count_upto(s, value, k - 1) + 1
+    } else {
+        count_upto(s, value, k - 1)
+    }
+}
+
+#[ensures(result == count_upto(s, value, s.len()))]
+#[ensures(result <= s.len())]
+pub fn count(s: &[i32], value: i32) -> usize {
+    let mut counter = 0;
+    let mut i = 0;
+    while i < s.len() {
+        body_invariant!(i < s.len());
+        body_invariant!(counter == count_upto(s, value, i));
+        body_invariant!(counter <= i);
+
+        if s[i] == value {
+            counter += 1;
+        }
+        i += 1;
+    }
+    counter
+}
+
+// 3. Searching: nothing in the prefix matched, otherwise we would have returned already
+#[ensures(match result {
+    Some(index) => index < s.len() && s[index] == value
+        && forall(|j: usize| j < index ==> s[j] != value),
+    None => forall(|j: usize| j < s.len() ==> s[j] != value),
+})]
+pub fn find(s: &[i32], value: i32) -> Option<usize> {
+    let mut i = 0;
+    while i < s.len() {
+        body_invariant!(i < s.len());
+        body_invariant!(forall(|j: usize| j < i ==> s[j] != value));
+
+        if s[i] == value {
+            return Some(i);
+        }
+        i += 1;
+    }
+    None
+}
+
+// 4. Filling: the prefix already has the new value. The length of a slice can't change,
+// but Prusti still needs to hear that the loop doesn't touch it.
+#[ensures(s.len() == old(s.len()))]
+#[ensures(forall(|j: usize| j < s.len() ==> s[j] == value))]
+pub fn fill(s: &mut [i32], value: i32) {
+    let mut i = 0;
+    while i < s.len() {
+        body_invariant!(i < s.len());
+        body_invariant!(s.len() == old(s.len()));
+        body_invariant!(forall(|j: usize| j < i ==> s[j] == value));
+
+        s[i] = value;
+        i += 1;
+    }
+}
+
+// 5. Ranges: `for i in 0..n { acc += i }` goes through `Range::next`, which Prusti has no
+// spec for (and the invariant would have to talk about the iterator's state). The same loop
+// written as a `while` has a counter the invariant can mention directly.
+// `acc` is the sum `0 + 1 + ... + (i - 1)`, stated without subtraction so it also holds for
+// `i == 0`. The bound on `n` keeps `n * n` (and so the sum) within `u64`; the assertion
+// checks this for each addition, as `Prusti.toml` turns off overflow checks.
+#[requires(n < 1 << 32)]
+#[ensures(n == 0 ==> result == 0)]
+#[ensures(n > 0 ==> result == n * (n - 1) / 2)]
+pub fn sum_below(n: u64) -> u64 {
+    let mut acc = 0;
+    let mut i = 0;
+    while i < n {
+        body_invariant!(i < n);
+        body_invariant!(2 * acc + i == i * i);
+
+        prusti_assert!(acc as u128 + i as u128 <= u64::MAX as u128);
+        acc += i;
+        i += 1;
+    }
+    acc
+}
+
+#[cfg(prusti)]
+mod prusti_tests {
+    use super::*;
+
+    fn _test_loops() {
+        let s = [1, 2, 3];
+        let total = sum(&s);
+        prusti_assert!(total == 6);
+
+        let t = [4, 7, 4, 1];
+        let n = count(&t, 4);
+        prusti_assert!(n == 2);
+
+        let found = find(&t, 7);
+        prusti_assert!(found === Some(1));
+        let missing = find(&t, 5);
+        prusti_assert!(missing.is_none());
+
+        let mut a = [0; 4];
+        fill(&mut a, 9);
+        prusti_assert!(a[0] == 9 && a[3] == 9);
+    }
+
+    fn _test_sum_below() {
+        let total = sum_below(4);
+        prusti_assert!(total == 6);
+        let empty = sum_below(0);
+        prusti_assert!(empty == 0);
+    }
+}
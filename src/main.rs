@@ -1,10 +1,143 @@
+// Prusti has no specs for the std helpers clippy suggests instead of plain
+// arithmetic and comparisons (`is_multiple_of`, `RangeInclusive::contains`, `clamp`)
+#![allow(clippy::manual_is_multiple_of, clippy::manual_range_contains, clippy::manual_clamp)]
+
+// Most modules are verification examples: many of their items are only used from specs
+// or from the `prusti_tests` modules, which plain `rustc` doesn't see
+#[allow(dead_code)]
 mod max;
+#[allow(dead_code)]
 mod testing;
 
 // mod list;
 // mod list_option;
+#[allow(dead_code)]
 mod list_generic_with_peek;
+#[allow(dead_code)]
+mod interpreter;
+mod std_specs;
+mod spec_macros;
+#[allow(dead_code)]
+mod binary;
+#[allow(dead_code)]
+mod calendar;
+#[allow(dead_code)]
+mod roman;
+#[allow(dead_code)]
+mod container;
+#[allow(dead_code)]
+mod stack;
+#[allow(dead_code)]
+mod pure_ord;
+#[allow(dead_code)]
+mod sorted_list;
+#[allow(dead_code)]
+mod bst;
+#[allow(dead_code)]
+mod closures;
+#[allow(dead_code)]
+mod int_sum;
+#[allow(dead_code)]
+mod verified_set;
+#[allow(dead_code)]
+mod loops;
+#[allow(dead_code)]
+mod assumptions;
+#[allow(dead_code)]
+mod logging;
+#[allow(dead_code)]
+mod frame;
+#[allow(dead_code)]
+mod partition;
+#[allow(dead_code)]
+mod quickselect;
+#[allow(dead_code)]
+mod assoc_list;
+#[allow(dead_code)]
+mod history;
+#[allow(dead_code)]
+mod array_list;
+#[allow(dead_code)]
+mod window;
+#[allow(dead_code)]
+mod counter;
+#[allow(dead_code)]
+mod bignum;
+#[allow(dead_code)]
+mod arith;
+#[allow(dead_code)]
+mod number_theory;
+#[allow(dead_code)]
+mod bits;
+#[allow(dead_code)]
+mod fixed_point;
+#[allow(dead_code)]
+mod units;
+#[allow(dead_code)]
+mod histogram;
+#[allow(dead_code)]
+mod priority_queue;
+#[allow(dead_code)]
+mod pairing_heap;
+#[allow(dead_code)]
+mod matrix;
+#[allow(dead_code)]
+mod graph;
+#[allow(dead_code)]
+mod interval;
+#[allow(dead_code)]
+mod string_builder;
+#[allow(dead_code)]
+mod text;
+#[allow(dead_code)]
+mod lemmas;
+#[allow(dead_code)]
+mod cached_list;
+#[allow(dead_code)]
+mod list_iter;
+#[allow(dead_code)]
+mod search;
+#[allow(dead_code)]
+mod list_error;
+#[allow(dead_code)]
+mod builder;
+#[allow(dead_code)]
+mod door;
+#[allow(dead_code)]
+mod handshake;
+#[allow(dead_code)]
+mod message_queue;
+#[allow(dead_code)]
+mod spsc;
+#[allow(dead_code)]
+mod counter_mutex;
+#[allow(dead_code)]
+mod shared_list;
+#[allow(dead_code)]
+mod slab;
+#[allow(dead_code)]
+mod slot_map;
+#[allow(dead_code)]
+mod free_list;
+#[allow(dead_code)]
+mod object_pool;
+#[allow(dead_code)]
+mod counted_box;
+#[allow(dead_code)]
+mod persistent;
+#[allow(dead_code)]
+mod checkpoint;
+#[allow(dead_code)]
+mod zipper;
+#[allow(dead_code)]
+mod cursor;
+#[allow(dead_code)]
+mod merge_sort;
+#[allow(dead_code)]
+mod list_closures;
+#[allow(dead_code)]
+mod list_ops;
 
 pub fn main() {
-    
-}
\ No newline at end of file
+
+}
@@ -0,0 +1,40 @@
+use prusti_contracts::*;
+
+// A fixed-size `R x C` matrix stored inline, with the same kind of frame condition
+// on `set` as `frame::Fixed`
+pub struct Matrix<T: Copy, const R: usize, const C: usize> {
+    cells: [[T; C]; R],
+}
+
+impl<T: Copy, const R: usize, const C: usize> Matrix<T, R, C> {
+    #[ensures(forall(|r: usize, c: usize| (r < R && c < C) ==> result.get(r, c) === value))]
+    pub fn new(value: T) -> Self {
+        Matrix { cells: [[value; C]; R] }
+    }
+
+    #[pure]
+    #[requires(row < R && col < C)]
+    pub fn get(&self, row: usize, col: usize) -> T {
+        self.cells[row][col]
+    }
+
+    #[requires(row < R && col < C)]
+    #[ensures(self.get(row, col) === value)]
+    #[ensures(forall(|r: usize, c: usize| (r < R && c < C && (r != row || c != col)) ==>
+        self.get(r, c) === old(self.get(r, c))))]
+    pub fn set(&mut self, row: usize, col: usize, value: T) {
+        self.cells[row][col] = value;
+    }
+}
+
+#[cfg(prusti)]
+mod prusti_tests {
+    use super::*;
+
+    fn _test_matrix() {
+        let mut m: Matrix<i32, 2, 3> = Matrix::new(0);
+        m.set(1, 2, 5);
+        prusti_assert!(m.get(1, 2) == 5 && m.get(0, 2) == 0 && m.get(1, 1) == 0);
+        // m.set(2, 0, 1); // FAILS: there are only 2 rows
+    }
+}
\ No newline at end of file
@@ -24,4 +24,35 @@ fn max3(a: i32, b: i32, c: i32) -> i32 {
             c
         }
     }
-}
\ No newline at end of file
+}
+
+// Generic version of `max`. Comparisons on any `T: Ord` are pure (see `std_specs`),
+// so the spec can talk about `a >= b` without knowing what `T` is.
+#[pure]
+#[ensures(a >= b ==> result === a)]
+#[ensures(!(a >= b) ==> result === b)]
+fn max_ord<T: Ord + Copy>(a: T, b: T) -> T {
+    if a >= b {
+        a
+    } else {
+        b
+    }
+}
+
+// For the primitive integers `>=` is specified exactly,
+// so the generic version is as good as the specialized one
+#[ensures(result == max(a, b))]
+fn max_i32(a: i32, b: i32) -> i32 {
+    max_ord(a, b)
+}
+
+#[ensures(result >= a && result >= b)]
+#[ensures(result == a || result == b)]
+fn max_u64(a: u64, b: u64) -> u64 {
+    max_ord(a, b)
+}
+
+#[ensures(result == max(a, max(b, c)))]
+fn max3_generic(a: i32, b: i32, c: i32) -> i32 {
+    max_ord(a, max_ord(b, c))
+}
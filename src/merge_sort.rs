@@ -0,0 +1,63 @@
+use prusti_contracts::*;
+
+use crate::list_generic_with_peek::List;
+// Predicates only exist when verifying
+#[cfg(prusti)]
+use crate::sorted_list::is_sorted;
+
+// Merges two sorted lists into one. The head of the result is one of the two heads,
+// which is what lets the caller put a smaller element in front of it.
+#[requires(is_sorted(&a) && is_sorted(&b))]
+#[ensures(is_sorted(&result))]
+#[ensures(result.len() == old(a.len()) + old(b.len()))]
+#[ensures(forall(|v: i32| result.count(&v) == old(a.count(&v)) + old(b.count(&v))))]
+#[ensures(!result.is_empty() ==>
+    (!old(a.is_empty()) && *result.lookup(0) == old(*a.lookup(0)))
+    || (!old(b.is_empty()) && *result.lookup(0) == old(*b.lookup(0))))]
+pub fn merge(mut a: List<i32>, mut b: List<i32>) -> List<i32> {
+    if a.is_empty() {
+        return b;
+    }
+    if b.is_empty() {
+        return a;
+    }
+    let smaller = if *a.lookup(0) <= *b.lookup(0) { a.pop() } else { b.pop() };
+    let mut result = merge(a, b);
+    // Both heads are at least `smaller`, so everything in `result` is
+    prusti_assert!(forall(|i: usize| i < result.len() ==> smaller <= *result.lookup(i)));
+    result.push(smaller);
+    result
+}
+
+impl List<i32> {
+    // Sorts the two halves and merges them. `split_off` and `merge` both keep the counts,
+    // so the result is a permutation of the input.
+    #[ensures(is_sorted(&result))]
+    #[ensures(result.len() == old(self.len()))]
+    #[ensures(forall(|v: i32| result.count(&v) == old(self.count(&v))))]
+    pub fn merge_sort(mut self) -> List<i32> {
+        let len = self.len();
+        if len <= 1 {
+            return self;
+        }
+        let right = self.split_off(len / 2);
+        merge(self.merge_sort(), right.merge_sort())
+    }
+}
+
+#[cfg(prusti)]
+mod prusti_tests {
+    use super::*;
+
+    fn _test_merge_sort() {
+        let mut list = List::new();
+        list.push(2);
+        list.push(7);
+        list.push(2);
+        list.push(-1);
+        let sorted = list.merge_sort();
+        prusti_assert!(sorted.len() == 4);
+        prusti_assert!(sorted.count(&2) == 2 && sorted.count(&7) == 1 && sorted.count(&-1) == 1);
+        prusti_assert!(*sorted.lookup(0) <= *sorted.lookup(1) && *sorted.lookup(2) <= *sorted.lookup(3));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,119 @@
+use prusti_contracts::*;
+
+use crate::assoc_list::AssocList;
+// The log only exists when verifying
+#[cfg(prusti)]
+use crate::list_generic_with_peek::List;
+
+// An in-memory message queue with at-most-once delivery.
+//
+// A message is sent, then delivered, then acknowledged. `pending` holds the messages that
+// weren't delivered yet, `unacked` the delivered ones waiting for their ack, and `log` the ids
+// of all deliveries so far. `delivered_count` counts in the log, and the type invariant ties it
+// to the two maps: a pending message was never delivered, an unacknowledged one exactly once,
+// and no message more than once.
+//
+// The log only exists for the specs. Like the count of `CountedBox`, it is a `cfg(prusti)`
+// field, so at runtime the queue only holds the messages that weren't acknowledged yet.
+#[invariant(forall(|id: u64| self.delivered_count(id) <= 1))]
+#[invariant(forall(|id: u64| self.pending.has_key(&id) ==> self.delivered_count(id) == 0))]
+#[invariant(forall(|id: u64| self.unacked.has_key(&id) ==> self.delivered_count(id) == 1))]
+pub struct MessageQueue {
+    pending: AssocList<u64, i32>,
+    unacked: AssocList<u64, i32>,
+    #[cfg(prusti)]
+    log: List<u64>,
+}
+
+impl MessageQueue {
+    #[ensures(forall(|id: u64| result.delivered_count(id) == 0 && !result.is_pending(id)))]
+    pub fn new() -> Self {
+        MessageQueue {
+            pending: AssocList::new(),
+            unacked: AssocList::new(),
+            #[cfg(prusti)]
+            log: List::new(),
+        }
+    }
+
+    #[cfg(prusti)]
+    #[pure]
+    pub fn delivered_count(&self, id: u64) -> usize {
+        self.log.count(&id)
+    }
+
+    predicate! {
+        pub fn is_pending(&self, id: u64) -> bool {
+            self.pending.has_key(&id)
+        }
+    }
+
+    predicate! {
+        pub fn is_unacked(&self, id: u64) -> bool {
+            self.unacked.has_key(&id)
+        }
+    }
+
+    // Ids can't be reused: a message with the id of a delivered one could never be delivered,
+    // and sending a pending id again would overwrite that message
+    #[requires(self.delivered_count(id) == 0 && !self.is_pending(id))]
+    #[ensures(self.is_pending(id))]
+    #[ensures(forall(|other: u64| self.delivered_count(other) == old(self.delivered_count(other))))]
+    pub fn send(&mut self, id: u64, msg: i32) {
+        self.pending.insert(id, msg);
+    }
+
+    // Hands out a pending message and moves it to the unacknowledged ones
+    #[ensures(match result {
+        Some(_) => old(self.is_pending(id)) && self.is_unacked(id) && self.delivered_count(id) == 1,
+        None => !old(self.is_pending(id)),
+    })]
+    #[ensures(!self.is_pending(id))]
+    #[ensures(forall(|other: u64| other != id ==> self.delivered_count(other) == old(self.delivered_count(other))))]
+    pub fn deliver(&mut self, id: u64) -> Option<i32> {
+        match self.pending.remove(&id) {
+            Some(msg) => {
+                #[cfg(prusti)]
+                {
+                    self.log.push(id);
+                }
+                self.unacked.insert(id, msg);
+                Some(msg)
+            }
+            None => None,
+        }
+    }
+
+    // An acknowledged message is gone for good: it is neither pending nor waiting for an ack,
+    // and since it stays in the log it can't be sent again. Acking an id that wasn't delivered
+    // does nothing, a pending message stays pending.
+    #[ensures(result == old(self.is_unacked(id)))]
+    #[ensures(!self.is_unacked(id))]
+    #[ensures(self.is_pending(id) == old(self.is_pending(id)))]
+    #[ensures(result ==> !self.is_pending(id))]
+    #[ensures(forall(|other: u64| self.delivered_count(other) == old(self.delivered_count(other))))]
+    pub fn ack(&mut self, id: u64) -> bool {
+        self.unacked.remove(&id).is_some()
+    }
+}
+
+#[cfg(prusti)]
+mod prusti_tests {
+    use super::*;
+
+    fn _test_message_queue() {
+        let mut queue = MessageQueue::new();
+        queue.send(1, 10);
+        let first = queue.deliver(1);
+        prusti_assert!(first.is_some());
+        // The second attempt finds nothing: the message was already delivered
+        let again = queue.deliver(1);
+        prusti_assert!(again.is_none());
+        prusti_assert!(queue.delivered_count(1) == 1);
+
+        let acked = queue.ack(1);
+        prusti_assert!(acked);
+        prusti_assert!(!queue.is_pending(1) && !queue.is_unacked(1));
+        // queue.send(1, 11); // FAILS: the id was already delivered
+    }
+}
\ No newline at end of file
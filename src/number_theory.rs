@@ -0,0 +1,120 @@
+use prusti_contracts::*;
+
+// Some `n % d` in `2..k` is zero
+#[pure]
+#[cfg_attr(prusti, terminates(Int::new(k as i64)))]
+pub fn has_divisor_below(n: u32, k: u32) -> bool {
+    if k <= 2 {
+        false
+    } else {
+        n % (k - 1) == 0 || has_divisor_below(n, k - 1)
+    }
+}
+
+// Lemma: a divisor below `m` is also below any `k >= m`
+#[pure]
+#[requires(m <= k && has_divisor_below(n, m))]
+#[ensures(has_divisor_below(n, k))]
+#[cfg_attr(prusti, terminates(Int::new(k as i64)))]
+#[allow(clippy::only_used_in_recursion)]
+fn lemma_divisor_below_monotone(n: u32, m: u32, k: u32) -> bool {
+    k == m || lemma_divisor_below_monotone(n, m, k - 1)
+}
+
+// Trial division by every candidate below `n`. The invariant collects
+// "no divisor found so far" as `!has_divisor_below(n, d)`.
+#[ensures(result == (n >= 2 && !has_divisor_below(n, n)))]
+pub fn is_prime(n: u32) -> bool {
+    if n < 2 {
+        return false;
+    }
+    let mut d = 2;
+    while d < n {
+        body_invariant!(2 <= d && d < n);
+        body_invariant!(!has_divisor_below(n, d));
+
+        if n % d == 0 {
+            // `d` is a divisor below `d + 1`, so also below `n`
+            prusti_assert!(lemma_divisor_below_monotone(n, d + 1, n));
+            return false;
+        }
+        d += 1;
+    }
+    true
+}
+
+// `3 * n + 1` still fits into a `u64`
+#[pure]
+pub fn can_step(n: u64) -> bool {
+    n % 2 == 0 || n <= (u64::MAX - 1) / 3
+}
+
+#[pure]
+#[requires(n >= 1 && can_step(n))]
+#[ensures(n % 2 == 0 ==> result == n / 2)]
+#[ensures(n % 2 == 1 ==> result == 3 * n + 1)]
+#[ensures(result >= 1)]
+pub fn collatz_step(n: u64) -> u64 {
+    if n % 2 == 0 {
+        n / 2
+    } else {
+        3 * n + 1
+    }
+}
+
+// The sequence starting at `n` gets to 1 within `k` steps (and without overflowing)
+#[pure]
+#[requires(n >= 1)]
+#[cfg_attr(prusti, terminates(Int::new(k as i64)))]
+pub fn reaches_one_within(n: u64, k: u64) -> bool {
+    n == 1 || (k > 0 && can_step(n) && reaches_one_within(collatz_step(n), k - 1))
+}
+
+// Nobody knows whether every sequence reaches 1, so this loop has no termination measure.
+// The fuel bounds the number of iterations instead, and the spec says honestly what
+// the result means: `Some` is the exact number of steps, `None` that the fuel wasn't enough
+// (or the next value would overflow).
+#[requires(n >= 1)]
+#[ensures(match result {
+    Some(steps) => steps <= fuel && reaches_one_within(n, steps),
+    None => !reaches_one_within(n, fuel),
+})]
+pub fn collatz_steps(n: u64, fuel: u64) -> Option<u64> {
+    let mut current = n;
+    let mut steps = 0;
+    while current != 1 {
+        body_invariant!(current >= 1 && current != 1 && steps <= fuel);
+        // The rest of the sequence is the sequence starting at `current`
+        body_invariant!(forall(|k: u64| k <= fuel - steps ==>
+            reaches_one_within(n, steps + k) == reaches_one_within(current, k)));
+
+        if steps == fuel || !can_step(current) {
+            return None;
+        }
+        current = collatz_step(current);
+        steps += 1;
+    }
+    Some(steps)
+}
+
+#[cfg(prusti)]
+mod prusti_tests {
+    use super::*;
+
+    fn _test_is_prime() {
+        let p = is_prime(7);
+        prusti_assert!(p);
+        let q = is_prime(9);
+        prusti_assert!(!q);
+        // A prime has no divisor in `2..n`
+        prusti_assert!(p ==> !has_divisor_below(7, 7));
+    }
+
+    fn _test_collatz() {
+        let none_needed = collatz_steps(1, 0);
+        prusti_assert!(none_needed === Some(0));
+        // 6 isn't 1, so it needs at least one step
+        let out_of_fuel = collatz_steps(6, 0);
+        prusti_assert!(out_of_fuel.is_none());
+    }
+}
\ No newline at end of file
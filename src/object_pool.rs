@@ -0,0 +1,88 @@
+use prusti_contracts::*;
+
+use crate::list_generic_with_peek::List;
+
+// A pool of reusable objects. The pool starts with all of them; `acquire` hands one out and
+// `release` takes one back. The type invariant is the bookkeeping: objects in the pool plus
+// objects handed out always make up the capacity, so the number handed out can't exceed it.
+//
+// The pool doesn't know which objects it handed out. `release` requires that something is
+// outstanding, which rules out giving back more objects than were taken.
+#[invariant(self.available.len() + self.outstanding == self.capacity)]
+pub struct ObjectPool<T> {
+    available: List<T>,
+    outstanding: usize,
+    capacity: usize,
+}
+
+impl<T> ObjectPool<T> {
+    #[ensures(result.capacity() == objects.len())]
+    #[ensures(result.outstanding() == 0)]
+    pub fn new(objects: List<T>) -> Self {
+        let capacity = objects.len();
+        ObjectPool { available: objects, outstanding: 0, capacity }
+    }
+
+    #[pure]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    #[pure]
+    #[ensures(result <= self.capacity())]
+    pub fn outstanding(&self) -> usize {
+        self.outstanding
+    }
+
+    #[pure]
+    #[ensures(result == self.capacity() - self.outstanding())]
+    pub fn available(&self) -> usize {
+        self.available.len()
+    }
+
+    #[ensures(self.capacity() == old(self.capacity()))]
+    #[ensures(match result {
+        Some(_) => old(self.available()) > 0 && self.outstanding() == old(self.outstanding()) + 1,
+        None => old(self.available()) == 0 && self.outstanding() == old(self.outstanding()),
+    })]
+    pub fn acquire(&mut self) -> Option<T> {
+        let object = self.available.try_pop();
+        if object.is_some() {
+            self.outstanding += 1;
+        }
+        object
+    }
+
+    // Exactly one slot becomes available again
+    #[requires(self.outstanding() > 0)]
+    #[ensures(self.capacity() == old(self.capacity()))]
+    #[ensures(self.outstanding() == old(self.outstanding()) - 1)]
+    #[ensures(self.available() == old(self.available()) + 1)]
+    pub fn release(&mut self, object: T) {
+        self.available.push(object);
+        self.outstanding -= 1;
+    }
+}
+
+#[cfg(prusti)]
+mod prusti_tests {
+    use super::*;
+
+    fn _test_object_pool() {
+        let mut objects = List::new();
+        objects.push(1);
+        objects.push(2);
+        let mut pool = ObjectPool::new(objects);
+
+        let a = pool.acquire();
+        let b = pool.acquire();
+        let c = pool.acquire();
+        prusti_assert!(a.is_some() && b.is_some() && c.is_none());
+        prusti_assert!(pool.outstanding() == 2);
+
+        pool.release(7);
+        prusti_assert!(pool.available() == 1);
+        pool.release(8);
+        // pool.release(9); // FAILS: nothing is outstanding
+    }
+}
\ No newline at end of file
@@ -0,0 +1,227 @@
+use prusti_contracts::*;
+
+use crate::list_generic_with_peek::List;
+
+// A pairing heap: a tree where every node is at most all nodes below it, stored as
+// "first child, next sibling" links. Merging and restructuring after `pop_min` shuffle
+// subtrees around in ways that are tedious to verify, so those two functions are
+// `#[trusted]`. Everything else, in particular the public interface, is verified against
+// their contracts. The model functions (`tree_len`, `tree_count`, `is_heap`) are
+// ordinary pure functions, so the contracts the trusted core promises are precise.
+
+type HeapLink = Option<Box<HeapNode>>;
+
+struct HeapNode {
+    elem: i32,
+    child: HeapLink,
+    sibling: HeapLink,
+}
+
+#[pure]
+#[cfg_attr(prusti, terminates(trusted))]
+fn tree_len(link: &HeapLink) -> usize {
+    match link {
+        None => 0,
+        Some(node) => 1 + tree_len(&node.child) + tree_len(&node.sibling),
+    }
+}
+
+#[pure]
+#[cfg_attr(prusti, terminates(trusted))]
+fn tree_count(link: &HeapLink, value: i32) -> usize {
+    match link {
+        None => 0,
+        Some(node) => {
+            let here = if node.elem == value { 1 } else { 0 };
+            here + tree_count(&node.child, value) + tree_count(&node.sibling, value)
+        }
+    }
+}
+
+// Every element is at least `bound`
+#[pure]
+#[cfg_attr(prusti, terminates(trusted))]
+fn all_geq(link: &HeapLink, bound: i32) -> bool {
+    match link {
+        None => true,
+        Some(node) => node.elem >= bound && all_geq(&node.child, bound) && all_geq(&node.sibling, bound),
+    }
+}
+
+// Every node is at most everything below it (its siblings are unrelated)
+#[pure]
+#[cfg_attr(prusti, terminates(trusted))]
+fn is_heap(link: &HeapLink) -> bool {
+    match link {
+        None => true,
+        Some(node) => all_geq(&node.child, node.elem) && is_heap(&node.child) && is_heap(&node.sibling),
+    }
+}
+
+// A single tree, not a list of siblings
+#[pure]
+fn is_root(link: &HeapLink) -> bool {
+    match link {
+        None => true,
+        Some(node) => node.sibling.is_none(),
+    }
+}
+
+// Lemma: a lower bound of all elements is below every element that occurs
+#[pure]
+#[requires(all_geq(link, bound))]
+#[ensures(tree_count(link, value) > 0 ==> bound <= value)]
+#[cfg_attr(prusti, terminates(trusted))]
+#[allow(clippy::only_used_in_recursion)]
+fn lemma_all_geq(link: &HeapLink, bound: i32, value: i32) -> bool {
+    match link {
+        None => true,
+        Some(node) => lemma_all_geq(&node.child, bound, value) && lemma_all_geq(&node.sibling, bound, value),
+    }
+}
+
+// The root with the larger element becomes the first child of the other one
+#[trusted]
+#[requires(is_root(&a) && is_heap(&a) && is_root(&b) && is_heap(&b))]
+#[ensures(is_root(&result) && is_heap(&result))]
+#[ensures(tree_len(&result) == tree_len(&a) + tree_len(&b))]
+#[ensures(forall(|v: i32| tree_count(&result, v) == tree_count(&a, v) + tree_count(&b, v)))]
+fn merge(a: HeapLink, b: HeapLink) -> HeapLink {
+    match (a, b) {
+        (None, b) => b,
+        (a, None) => a,
+        (Some(mut x), Some(mut y)) => {
+            if x.elem <= y.elem {
+                y.sibling = x.child.take();
+                x.child = Some(y);
+                Some(x)
+            } else {
+                x.sibling = y.child.take();
+                y.child = Some(x);
+                Some(y)
+            }
+        }
+    }
+}
+
+// Merges a list of sibling trees pairwise into a single tree
+#[trusted]
+#[requires(is_heap(&first))]
+#[ensures(is_root(&result) && is_heap(&result))]
+#[ensures(tree_len(&result) == tree_len(&first))]
+#[ensures(forall(|v: i32| tree_count(&result, v) == tree_count(&first, v)))]
+fn merge_pairs(first: HeapLink) -> HeapLink {
+    match first {
+        None => None,
+        Some(mut a) => match a.sibling.take() {
+            None => Some(a),
+            Some(mut b) => {
+                let rest = b.sibling.take();
+                merge(merge(Some(a), Some(b)), merge_pairs(rest))
+            }
+        },
+    }
+}
+
+#[invariant(is_root(&self.root) && is_heap(&self.root))]
+pub struct PairingHeap {
+    root: HeapLink,
+}
+
+impl PairingHeap {
+    #[ensures(result.len() == 0)]
+    pub fn new() -> Self {
+        PairingHeap { root: None }
+    }
+
+    #[pure]
+    pub fn len(&self) -> usize {
+        tree_len(&self.root)
+    }
+
+    // Number of occurrences of `value`: the contents as a multiset
+    #[pure]
+    pub fn count(&self, value: i32) -> usize {
+        tree_count(&self.root, value)
+    }
+
+    #[pure]
+    #[requires(self.len() > 0)]
+    #[ensures(self.count(result) > 0)]
+    #[ensures(forall(|v: i32| self.count(v) > 0 ==> result <= v))]
+    pub fn min(&self) -> i32 {
+        match &self.root {
+            Some(node) => {
+                prusti_assert!(forall(|v: i32| lemma_all_geq(&node.child, node.elem, v)));
+                node.elem
+            }
+            None => unreachable!(),
+        }
+    }
+
+    #[ensures(self.len() == old(self.len()) + 1)]
+    #[ensures(self.count(elem) == old(self.count(elem)) + 1)]
+    #[ensures(forall(|v: i32| v != elem ==> self.count(v) == old(self.count(v))))]
+    pub fn push(&mut self, elem: i32) {
+        let single = Some(Box::new(HeapNode { elem, child: None, sibling: None }));
+        self.root = merge(self.root.take(), single);
+    }
+
+    // Removes and returns the minimum of everything in the heap
+    #[requires(self.len() > 0)]
+    #[ensures(result == old(self.min()))]
+    #[ensures(forall(|v: i32| old(self.count(v)) > 0 ==> result <= v))]
+    #[ensures(self.len() == old(self.len()) - 1)]
+    #[ensures(self.count(result) == old(self.count(result)) - 1)]
+    #[ensures(forall(|v: i32| v != result ==> self.count(v) == old(self.count(v))))]
+    pub fn pop_min(&mut self) -> i32 {
+        match self.root.take() {
+            Some(node) => {
+                let node = *node;
+                self.root = merge_pairs(node.child);
+                node.elem
+            }
+            None => unreachable!(),
+        }
+    }
+}
+
+// The compiler-generated drop recurses into both links of every node, so a long chain of
+// children or siblings (pushing ascending elements builds one) overflows the stack.
+// Instead, the links are moved onto a worklist, and each node is dropped after both of its
+// links were taken.
+impl Drop for PairingHeap {
+    fn drop(&mut self) {
+        let mut worklist = List::new();
+        if let Some(root) = self.root.take() {
+            worklist.push(root);
+        }
+        while let Some(mut node) = worklist.try_pop() {
+            if let Some(child) = node.child.take() {
+                worklist.push(child);
+            }
+            if let Some(sibling) = node.sibling.take() {
+                worklist.push(sibling);
+            }
+        }
+    }
+}
+
+#[cfg(prusti)]
+mod prusti_tests {
+    use super::*;
+
+    fn _test_pairing_heap() {
+        let mut heap = PairingHeap::new();
+        heap.push(5);
+        heap.push(2);
+        heap.push(8);
+        prusti_assert!(heap.len() == 3);
+        prusti_assert!(heap.count(2) == 1 && heap.count(5) == 1 && heap.count(8) == 1);
+
+        // 2 is in the heap, and the minimum is below everything in it
+        let x = heap.pop_min();
+        prusti_assert!(x <= 2);
+        prusti_assert!(heap.len() == 2);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,149 @@
+use prusti_contracts::*;
+
+use crate::spec_macros::ensures_unchanged;
+
+// Lomuto partitioning of a range of a `Vec`, the building block of quickselect and quicksort.
+//
+// The result is only useful if the range still holds the same values, so besides the
+// ordering around the pivot the spec also says where every element came from, and that
+// `count_lt`/`count_leq` are the same for every `x` (the values are only reordered).
+
+predicate! {
+    // Every element of `v[lo..hi]` was already somewhere in `prev[lo..hi]`
+    pub fn elements_from_range(v: &Vec<i32>, prev: &Vec<i32>, lo: usize, hi: usize) -> bool {
+        forall(|i: usize| (lo <= i && i < hi) ==>
+            exists(|j: usize| lo <= j && j < hi && v[i] == prev[j]))
+    }
+}
+
+predicate! {
+    // Everything outside of `lo..hi` is untouched
+    pub fn unchanged_outside(v: &Vec<i32>, prev: &Vec<i32>, lo: usize, hi: usize) -> bool {
+        v.len() == prev.len()
+        && forall(|i: usize| (i < v.len() && (i < lo || hi <= i)) ==> v[i] == prev[i])
+    }
+}
+
+// Number of elements of `s[..n]` that are below `x`
+#[pure]
+#[requires(n <= s.len())]
+#[ensures(result <= n)]
+#[cfg_attr(prusti, terminates(Int::new_usize(n)))]
+pub fn count_lt(s: &[i32], x: i32, n: usize) -> usize {
+    if n == 0 {
+        0
+    } else if s[n - 1] < x {
+        count_lt(s, x, n - 1) + 1
+    } else {
+        count_lt(s, x, n - 1)
+    }
+}
+
+// Number of elements of `s[..n]` that are at most `x`
+#[pure]
+#[requires(n <= s.len())]
+#[ensures(result <= n)]
+#[cfg_attr(prusti, terminates(Int::new_usize(n)))]
+pub fn count_leq(s: &[i32], x: i32, n: usize) -> usize {
+    if n == 0 {
+        0
+    } else if s[n - 1] <= x {
+        count_leq(s, x, n - 1) + 1
+    } else {
+        count_leq(s, x, n - 1)
+    }
+}
+
+// Lemma: a swap of `i <= j` exchanges `prev[i]` for `prev[j]` in the prefixes that end
+// between them and changes no other prefix. For `n == v.len()` the counts are the same.
+#[pure]
+#[requires(i <= j && j < v.len() && n <= v.len() && v.len() == prev.len())]
+#[requires(v[i] == prev[j] && v[j] == prev[i])]
+#[requires(forall(|k: usize| (k < v.len() && k != i && k != j) ==> v[k] == prev[k]))]
+#[ensures(if i < n && n <= j {
+    count_lt(v, x, n) + (if prev[i] < x { 1 } else { 0 }) == count_lt(prev, x, n) + (if prev[j] < x { 1 } else { 0 })
+    && count_leq(v, x, n) + (if prev[i] <= x { 1 } else { 0 }) == count_leq(prev, x, n) + (if prev[j] <= x { 1 } else { 0 })
+} else {
+    count_lt(v, x, n) == count_lt(prev, x, n) && count_leq(v, x, n) == count_leq(prev, x, n)
+})]
+#[cfg_attr(prusti, terminates(Int::new_usize(n)))]
+#[allow(clippy::only_used_in_recursion)]
+fn lemma_swap_counts(v: &[i32], prev: &[i32], i: usize, j: usize, x: i32, n: usize) -> bool {
+    n == 0 || lemma_swap_counts(v, prev, i, j, x, n - 1)
+}
+
+ensures_unchanged! {
+    |k| v[k], v.len(), except i, j;
+    #[requires(i < v.len() && j < v.len())]
+    #[ensures(v.len() == old(v.len()))]
+    #[ensures(v[i] == old(v[j]) && v[j] == old(v[i]))]
+    #[ensures(forall(|x: i32| count_lt(v.as_slice(), x, v.len()) == old(count_lt(v.as_slice(), x, v.len()))))]
+    #[ensures(forall(|x: i32| count_leq(v.as_slice(), x, v.len()) == old(count_leq(v.as_slice(), x, v.len()))))]
+    // Written by hand against the `Vec` extern specs: Prusti has no spec for `<[T]>::swap`
+    #[allow(clippy::ptr_arg, clippy::manual_swap)]
+    pub fn swap(v: &mut Vec<i32>, i: usize, j: usize) {
+        #[cfg(prusti)]
+        let before = ghost!(snap(v));
+
+        let tmp = v[i];
+        v[i] = v[j];
+        v[j] = tmp;
+        prusti_assert!(forall(|x: i32| if i <= j {
+            lemma_swap_counts(v.as_slice(), before.as_slice(), i, j, x, v.len())
+        } else {
+            lemma_swap_counts(v.as_slice(), before.as_slice(), j, i, x, v.len())
+        }));
+    }
+}
+
+// Uses the last element of the range as the pivot and returns its final position.
+// Elements below the pivot end up in front of it, all others behind it.
+#[requires(lo < hi && hi <= v.len())]
+#[ensures(lo <= result && result < hi)]
+#[ensures(forall(|i: usize| (lo <= i && i < result) ==> v[i] < v[result]))]
+#[ensures(forall(|i: usize| (result < i && i < hi) ==> v[i] >= v[result]))]
+#[ensures(elements_from_range(v, &old(snap(v)), lo, hi))]
+#[ensures(unchanged_outside(v, &old(snap(v)), lo, hi))]
+#[ensures(forall(|x: i32| count_lt(v.as_slice(), x, v.len()) == old(count_lt(v.as_slice(), x, v.len()))))]
+#[ensures(forall(|x: i32| count_leq(v.as_slice(), x, v.len()) == old(count_leq(v.as_slice(), x, v.len()))))]
+pub fn partition(v: &mut Vec<i32>, lo: usize, hi: usize) -> usize {
+    let pivot = v[hi - 1];
+    let mut store = lo;
+    let mut i = lo;
+    while i < hi - 1 {
+        body_invariant!(lo <= store && store <= i && i < hi - 1);
+        body_invariant!(v[hi - 1] == pivot);
+        // `v[lo..store]` is below the pivot, `v[store..i]` is not
+        body_invariant!(forall(|j: usize| (lo <= j && j < store) ==> v[j] < pivot));
+        body_invariant!(forall(|j: usize| (store <= j && j < i) ==> v[j] >= pivot));
+        body_invariant!(elements_from_range(v, &old(snap(v)), lo, hi));
+        body_invariant!(unchanged_outside(v, &old(snap(v)), lo, hi));
+        body_invariant!(forall(|x: i32| count_lt(v.as_slice(), x, v.len()) == old(count_lt(v.as_slice(), x, v.len()))));
+        body_invariant!(forall(|x: i32| count_leq(v.as_slice(), x, v.len()) == old(count_leq(v.as_slice(), x, v.len()))));
+
+        if v[i] < pivot {
+            swap(v, i, store);
+            store += 1;
+        }
+        i += 1;
+    }
+    swap(v, store, hi - 1);
+    store
+}
+
+#[cfg(prusti)]
+mod prusti_tests {
+    use super::*;
+
+    fn _test_partition() {
+        let mut v = Vec::new();
+        v.push(5);
+        v.push(1);
+        v.push(4);
+        v.push(3);
+        let p = partition(&mut v, 0, 4);
+        // The pivot (the last element, 3) is at `p` now
+        prusti_assert!(v.len() == 4 && p < 4);
+        prusti_assert!(forall(|i: usize| i < p ==> v[i] < v[p]));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,205 @@
+use prusti_contracts::*;
+use std::rc::Rc;
+
+// A persistent list: `cons` and `tail` make new lists that share their nodes with the old one,
+// so both stay usable and nothing is copied. The nodes are reference counted (`Rc`).
+//
+// Prusti can't look through an `Rc`, so the functions that touch the nodes are trusted. Their
+// contracts use the same vocabulary as `List`: `len` and `lookup`. Code built on top of them,
+// like `CowList`, is verified against these contracts.
+
+type PLink<T> = Option<Rc<PNode<T>>>;
+
+#[derive(Clone)]
+struct PNode<T> {
+    elem: T,
+    next: PLink<T>,
+}
+
+pub struct PList<T> {
+    head: PLink<T>,
+}
+
+#[trusted]
+fn plink_len<T>(link: &PLink<T>) -> usize {
+    match link {
+        None => 0,
+        Some(node) => 1 + plink_len(&node.next),
+    }
+}
+
+#[trusted]
+fn plink_lookup<T>(link: &PLink<T>, index: usize) -> &T {
+    match link {
+        Some(node) if index == 0 => &node.elem,
+        Some(node) => plink_lookup(&node.next, index - 1),
+        None => unreachable!(),
+    }
+}
+
+impl<T> PList<T> {
+    #[trusted]
+    #[ensures(result.len() == 0)]
+    pub fn new() -> Self {
+        PList { head: None }
+    }
+
+    #[trusted]
+    #[pure]
+    pub fn len(&self) -> usize {
+        plink_len(&self.head)
+    }
+
+    #[pure]
+    #[ensures(result == (self.len() == 0))]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    #[trusted]
+    #[pure]
+    #[requires(index < self.len())]
+    pub fn lookup(&self, index: usize) -> &T {
+        plink_lookup(&self.head, index)
+    }
+
+    predicate! {
+        // Same length and the same elements
+        pub fn same_as(&self, other: &Self) -> bool {
+            self.len() == other.len()
+            && forall(|i: usize| i < self.len() ==> self.lookup(i) === other.lookup(i))
+        }
+    }
+
+    // `self` with `elem` in front. The new list shares all nodes of `self`.
+    #[trusted]
+    #[ensures(result.len() == self.len() + 1)]
+    #[ensures(snap(result.lookup(0)) === elem)]
+    #[ensures(forall(|i: usize| i < self.len() ==> result.lookup(i + 1) === self.lookup(i)))]
+    pub fn cons(&self, elem: T) -> Self {
+        PList { head: Some(Rc::new(PNode { elem, next: self.head.clone() })) }
+    }
+
+    #[trusted]
+    #[requires(!self.is_empty())]
+    #[ensures(result.len() == self.len() - 1)]
+    #[ensures(forall(|i: usize| i < result.len() ==> result.lookup(i) === self.lookup(i + 1)))]
+    pub fn tail(&self) -> Self {
+        match &self.head {
+            Some(node) => PList { head: node.next.clone() },
+            None => unreachable!(),
+        }
+    }
+
+    // Another list with the same nodes, in constant time
+    #[trusted]
+    #[ensures(result.same_as(self))]
+    pub fn share(&self) -> Self {
+        PList { head: self.head.clone() }
+    }
+}
+
+impl<T: Clone> PList<T> {
+    // `Rc::make_mut` on the first node: it is copied first if another list shares it.
+    // Either way the contents are the same as before. Whether a node is shared depends on the
+    // other lists, not on `self`, so it isn't part of the spec.
+    #[trusted]
+    #[requires(!self.is_empty())]
+    #[ensures(snap(result) === old(snap(self.lookup(0))))]
+    #[after_expiry(
+        self.len() == old(self.len())
+        && snap(self.lookup(0)) === before_expiry(snap(result))
+        && forall(|i: usize| (0 < i && i < self.len()) ==> self.lookup(i) === old(self.lookup(i)))
+    )]
+    pub fn head_mut(&mut self) -> &mut T {
+        match &mut self.head {
+            Some(node) => &mut Rc::make_mut(node).elem,
+            None => unreachable!(),
+        }
+    }
+}
+
+// A list that can be copied in constant time and is only really copied when one of the
+// copies changes: copy-on-write. Changing the first element copies at most one node, the
+// rest stays shared.
+pub struct CowList<T: Clone> {
+    list: PList<T>,
+}
+
+impl<T: Clone> CowList<T> {
+    #[ensures(result.len() == 0)]
+    pub fn new() -> Self {
+        CowList { list: PList::new() }
+    }
+
+    #[pure]
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    #[pure]
+    #[requires(index < self.len())]
+    pub fn lookup(&self, index: usize) -> &T {
+        self.list.lookup(index)
+    }
+
+    #[ensures(result.len() == self.len())]
+    #[ensures(forall(|i: usize| i < self.len() ==> result.lookup(i) === self.lookup(i)))]
+    pub fn share(&self) -> Self {
+        CowList { list: self.list.share() }
+    }
+
+    #[ensures(self.len() == old(self.len()) + 1)]
+    #[ensures(snap(self.lookup(0)) === elem)]
+    #[ensures(forall(|i: usize| i < old(self.len()) ==> self.lookup(i + 1) === old(self.lookup(i))))]
+    pub fn push(&mut self, elem: T) {
+        self.list = self.list.cons(elem);
+    }
+
+    // Mutable access to the first element. Whether this copies a node or not can't be seen
+    // in the contents: they are exactly as before until the reference is written to.
+    #[requires(self.len() > 0)]
+    #[ensures(snap(result) === old(snap(self.lookup(0))))]
+    #[after_expiry(
+        self.len() == old(self.len())
+        && snap(self.lookup(0)) === before_expiry(snap(result))
+        && forall(|i: usize| (0 < i && i < self.len()) ==> self.lookup(i) === old(self.lookup(i)))
+    )]
+    pub fn make_mut(&mut self) -> &mut T {
+        self.list.head_mut()
+    }
+
+    #[requires(self.len() > 0)]
+    #[ensures(self.len() == old(self.len()))]
+    #[ensures(snap(self.lookup(0)) === value)]
+    #[ensures(forall(|i: usize| (0 < i && i < self.len()) ==> self.lookup(i) === old(self.lookup(i))))]
+    pub fn set_head(&mut self, value: T) {
+        *self.make_mut() = value;
+    }
+}
+
+#[cfg(prusti)]
+mod prusti_tests {
+    use super::*;
+
+    fn _test_persistent() {
+        let empty = PList::new();
+        let one = empty.cons(1);
+        let two = one.cons(2);
+        // `one` is still intact, and is the tail of `two`
+        prusti_assert!(one.len() == 1 && *one.lookup(0) == 1);
+        let rest = two.tail();
+        prusti_assert!(rest.same_as(&one));
+    }
+
+    fn _test_cow_list() {
+        let mut a = CowList::new();
+        a.push(1);
+        a.push(2);
+        let mut b = a.share();
+        b.set_head(5);
+        prusti_assert!(*b.lookup(0) == 5 && *b.lookup(1) == 1);
+        // The copy was made for `b`, `a` didn't change
+        prusti_assert!(*a.lookup(0) == 2);
+    }
+}
\ No newline at end of file
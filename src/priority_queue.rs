@@ -0,0 +1,72 @@
+use prusti_contracts::*;
+
+use crate::sorted_list::SortedList;
+
+// A min-priority queue of integers. The sorted list does all the work: its smallest
+// element is always at the front. Clients see the contents as a multiset through `count`.
+pub struct PriorityQueue {
+    elems: SortedList<i32>,
+}
+
+impl PriorityQueue {
+    #[ensures(result.len() == 0)]
+    pub fn new() -> Self {
+        PriorityQueue { elems: SortedList::new() }
+    }
+
+    #[pure]
+    pub fn len(&self) -> usize {
+        self.elems.len()
+    }
+
+    #[pure]
+    #[requires(index < self.len())]
+    pub fn lookup(&self, index: usize) -> i32 {
+        *self.elems.lookup(index)
+    }
+
+    #[pure]
+    pub fn count(&self, value: i32) -> usize {
+        self.elems.count(&value)
+    }
+
+    #[ensures(self.len() == old(self.len()) + 1)]
+    #[ensures(self.count(elem) == old(self.count(elem)) + 1)]
+    #[ensures(forall(|v: i32| v != elem ==> self.count(v) == old(self.count(v))))]
+    pub fn push(&mut self, elem: i32) {
+        self.elems.insert_sorted(elem);
+    }
+
+    // Returns the minimum of everything that was in the queue
+    #[requires(self.len() > 0)]
+    #[ensures(self.len() == old(self.len()) - 1)]
+    #[ensures(result == old(self.lookup(0)))]
+    #[ensures(forall(|i: usize| i < old(self.len()) ==> result <= old(self.lookup(i))))]
+    #[ensures(forall(|i: usize| i < self.len() ==> result <= self.lookup(i)))]
+    #[ensures(self.count(result) == old(self.count(result)) - 1)]
+    #[ensures(forall(|v: i32| v != result ==> self.count(v) == old(self.count(v))))]
+    pub fn pop_min(&mut self) -> i32 {
+        // The minimum of the sorted list is below everything, including itself
+        prusti_assert!(forall(|i: usize| i < self.len() ==> *self.elems.min() <= self.lookup(i)));
+        self.elems.pop_min()
+    }
+}
+
+#[cfg(prusti)]
+mod prusti_tests {
+    use super::*;
+
+    fn _test_priority_queue() {
+        let mut queue = PriorityQueue::new();
+        queue.push(5);
+        queue.push(2);
+        queue.push(8);
+        prusti_assert!(queue.count(2) == 1 && queue.len() == 3);
+
+        let min = queue.lookup(0);
+        let x = queue.pop_min();
+        prusti_assert!(x == min && queue.len() == 2);
+        prusti_assert!(x <= queue.lookup(0) && x <= queue.lookup(1));
+        // PriorityQueue::new().pop_min(); // FAILS: the queue is empty
+    }
+}
\ No newline at end of file
@@ -0,0 +1,67 @@
+use prusti_contracts::*;
+
+// A total preorder that can be used in specifications.
+//
+// Generic specs can't assume anything about `T: Ord` (an implementation could be wrong),
+// so the ordering axioms are turned into lemma methods. Every implementation has to prove
+// them, and generic code calls them (inside `prusti_assert!`) wherever it needs the axiom.
+pub trait PureOrd {
+    #[pure]
+    fn leq(&self, other: &Self) -> bool;
+
+    // Both elements are as large as each other
+    #[pure]
+    fn equiv(&self, other: &Self) -> bool {
+        self.leq(other) && other.leq(self)
+    }
+
+    // Lemma: any two elements are comparable
+    #[pure]
+    #[ensures(self.leq(other) || other.leq(self))]
+    fn total(&self, other: &Self) -> bool;
+
+    // Lemma: `leq` is transitive
+    #[pure]
+    #[requires(self.leq(b) && b.leq(c))]
+    #[ensures(self.leq(c))]
+    fn transitive(&self, b: &Self, c: &Self) -> bool;
+}
+
+// For the primitive integers the lemmas follow directly from integer arithmetic
+#[refine_trait_spec]
+impl PureOrd for i32 {
+    #[pure]
+    #[ensures(result == (*self <= *other))]
+    fn leq(&self, other: &Self) -> bool {
+        *self <= *other
+    }
+
+    #[pure]
+    fn total(&self, _other: &Self) -> bool {
+        true
+    }
+
+    #[pure]
+    fn transitive(&self, _b: &Self, _c: &Self) -> bool {
+        true
+    }
+}
+
+#[refine_trait_spec]
+impl PureOrd for u64 {
+    #[pure]
+    #[ensures(result == (*self <= *other))]
+    fn leq(&self, other: &Self) -> bool {
+        *self <= *other
+    }
+
+    #[pure]
+    fn total(&self, _other: &Self) -> bool {
+        true
+    }
+
+    #[pure]
+    fn transitive(&self, _b: &Self, _c: &Self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,106 @@
+use prusti_contracts::*;
+
+use crate::partition::partition;
+// Predicates only exist when verifying, and the counts only appear in specs
+#[cfg(prusti)]
+use crate::partition::{count_leq, count_lt, elements_from_range, unchanged_outside};
+
+// The k-th smallest element (counting from 0) is characterised by counting:
+// fewer than `k + 1` elements are strictly below it, but at least `k + 1` are at most it.
+// That is independent of the order of `v`, which quickselect changes. The counts are
+// defined in `partition`, which keeps them the same for every `x`.
+
+// Lemma: elements that are not below `x` don't add to `count_lt`
+#[pure]
+#[requires(m <= n && n <= s.len())]
+#[requires(forall(|i: usize| (m <= i && i < n) ==> s[i] >= x))]
+#[ensures(count_lt(s, x, n) == count_lt(s, x, m))]
+#[cfg_attr(prusti, terminates(Int::new_usize(n)))]
+#[allow(clippy::only_used_in_recursion)]
+fn lemma_lt_unchanged(s: &[i32], x: i32, m: usize, n: usize) -> bool {
+    n == m || lemma_lt_unchanged(s, x, m, n - 1)
+}
+
+// Lemma: if all of `s[..n]` is at most `x`, all of it is counted
+#[pure]
+#[requires(n <= s.len())]
+#[requires(forall(|i: usize| i < n ==> s[i] <= x))]
+#[ensures(count_leq(s, x, n) == n)]
+#[cfg_attr(prusti, terminates(Int::new_usize(n)))]
+#[allow(clippy::only_used_in_recursion)]
+fn lemma_leq_all(s: &[i32], x: i32, n: usize) -> bool {
+    n == 0 || lemma_leq_all(s, x, n - 1)
+}
+
+// Lemma: counting a longer prefix never gives less
+#[pure]
+#[requires(m <= n && n <= s.len())]
+#[ensures(count_leq(s, x, m) <= count_leq(s, x, n))]
+#[cfg_attr(prusti, terminates(Int::new_usize(n)))]
+#[allow(clippy::only_used_in_recursion)]
+fn lemma_leq_monotonic(s: &[i32], x: i32, m: usize, n: usize) -> bool {
+    n == m || lemma_leq_monotonic(s, x, m, n - 1)
+}
+
+// Only the range `lo..hi` that contains `k` is partitioned again. Everything in front of
+// the range is at most everything from `lo` on, and everything behind it is at least
+// everything up to `hi`, so the element that ends up at `k` is in its sorted position.
+#[requires(k < v.len())]
+#[ensures(v.len() == old(v.len()))]
+// `v` is only reordered, so the bounds below hold for the input just as well
+#[ensures(forall(|x: i32| count_lt(v.as_slice(), x, v.len()) == old(count_lt(v.as_slice(), x, v.len()))))]
+#[ensures(forall(|x: i32| count_leq(v.as_slice(), x, v.len()) == old(count_leq(v.as_slice(), x, v.len()))))]
+#[ensures(count_lt(v.as_slice(), result, v.len()) <= k)]
+#[ensures(k < count_leq(v.as_slice(), result, v.len()))]
+pub fn kth_smallest(v: &mut Vec<i32>, k: usize) -> i32 {
+    let mut lo = 0;
+    let mut hi = v.len();
+    while lo + 1 < hi {
+        body_invariant!(lo <= k && k < hi && hi <= v.len());
+        body_invariant!(v.len() == old(v.len()));
+        body_invariant!(forall(|x: i32| count_lt(v.as_slice(), x, v.len()) == old(count_lt(v.as_slice(), x, v.len()))));
+        body_invariant!(forall(|x: i32| count_leq(v.as_slice(), x, v.len()) == old(count_leq(v.as_slice(), x, v.len()))));
+        body_invariant!(forall(|i: usize, j: usize| (i < lo && lo <= j && j < v.len()) ==> v[i] <= v[j]));
+        body_invariant!(forall(|i: usize, j: usize| (i < hi && hi <= j && j < v.len()) ==> v[i] <= v[j]));
+
+        #[cfg(prusti)]
+        let before = ghost!(snap(v));
+        let p = partition(v, lo, hi);
+        // The partitioned range holds the same values as before, so the bounds
+        // against the elements outside of it still hold
+        prusti_assert!(elements_from_range(v, &before, lo, hi) && unchanged_outside(v, &before, lo, hi));
+
+        if p == k {
+            lo = k;
+            hi = k + 1;
+        } else if k < p {
+            hi = p;
+        } else {
+            lo = p + 1;
+        }
+    }
+
+    // Now `lo == k`: everything in front of `k` is at most `v[k]`, everything behind it at least
+    let result = v[k];
+    prusti_assert!(lemma_lt_unchanged(v.as_slice(), result, k, v.len()));
+    prusti_assert!(lemma_leq_all(v.as_slice(), result, k + 1));
+    prusti_assert!(lemma_leq_monotonic(v.as_slice(), result, k + 1, v.len()));
+    result
+}
+
+#[cfg(prusti)]
+mod prusti_tests {
+    use super::*;
+
+    fn _test_kth_smallest() {
+        let mut v = Vec::new();
+        v.push(7);
+        v.push(2);
+        v.push(9);
+        v.push(4);
+        let x = kth_smallest(&mut v, 1);
+        // At most one element is below `x`, at least two are at most `x`
+        prusti_assert!(count_lt(v.as_slice(), x, v.len()) <= 1);
+        prusti_assert!(count_leq(v.as_slice(), x, v.len()) >= 2);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,204 @@
+use prusti_contracts::*;
+
+// Roman numerals as ASCII bytes, e.g. `b"MCMXCIV"` for 1994.
+
+#[pure]
+#[ensures(result <= 1000)]
+fn letter_value(c: u8) -> u32 {
+    match c {
+        b'I' => 1,
+        b'V' => 5,
+        b'X' => 10,
+        b'L' => 50,
+        b'C' => 100,
+        b'D' => 500,
+        b'M' => 1000,
+        _ => 0,
+    }
+}
+
+predicate! {
+    // The first `k` bytes are all roman letters
+    fn is_roman_upto(s: &[u8], k: usize) -> bool {
+        k <= s.len()
+        && forall(|i: usize| i < k ==> letter_value(s[i]) > 0)
+    }
+}
+
+predicate! {
+    pub fn is_roman(s: &[u8]) -> bool {
+        is_roman_upto(s, s.len())
+    }
+}
+
+// What appending `s[k - 1]` adds to the value of `s[..k - 1]`.
+// A letter that is smaller than its successor is subtracted instead of added,
+// so it was counted once too often and has to be taken away twice.
+#[pure]
+#[requires(1 <= k && k <= s.len())]
+#[ensures(result >= 0 && result <= 1000)]
+fn letter_contribution(s: &[u8], k: usize) -> i64 {
+    let value = letter_value(s[k - 1]) as i64;
+    if k >= 2 && letter_value(s[k - 2]) < letter_value(s[k - 1]) {
+        value - 2 * letter_value(s[k - 2]) as i64
+    } else {
+        value
+    }
+}
+
+// Value of the numeral `s[..k]`, read from left to right
+#[pure]
+#[requires(k <= s.len())]
+#[cfg_attr(prusti, terminates(Int::new_usize(k)))]
+pub fn roman_value_upto(s: &[u8], k: usize) -> i64 {
+    if k == 0 {
+        0
+    } else {
+        roman_value_upto(s, k - 1) + letter_contribution(s, k)
+    }
+}
+
+#[pure]
+pub fn roman_value(s: &[u8]) -> i64 {
+    roman_value_upto(s, s.len())
+}
+
+// Lemma: the value of a prefix only depends on the letters inside that prefix
+#[pure]
+#[requires(k <= a.len() && k <= b.len())]
+#[requires(forall(|i: usize| i < k ==> a[i] == b[i]))]
+#[ensures(roman_value_upto(a, k) == roman_value_upto(b, k))]
+#[cfg_attr(prusti, terminates(Int::new_usize(k)))]
+#[allow(clippy::only_used_in_recursion)]
+fn lemma_prefix_value(a: &[u8], b: &[u8], k: usize) -> bool {
+    k == 0 || lemma_prefix_value(a, b, k - 1)
+}
+
+#[pure]
+#[requires(s.len() > 0)]
+fn last_value(s: &[u8]) -> u32 {
+    letter_value(s[s.len() - 1])
+}
+
+#[requires(letter_value(c) > 0)]
+#[requires(is_roman(out.as_slice()))]
+#[ensures(out.len() == old(out.len()) + 1)]
+#[ensures(is_roman(out.as_slice()))]
+#[ensures(last_value(out.as_slice()) == letter_value(c))]
+#[ensures(lemma_prefix_value(old(snap(out)).as_slice(), out.as_slice(), old(out.len())))]
+#[ensures(roman_value(out.as_slice()) ==
+    old(roman_value(out.as_slice())) + letter_contribution(out.as_slice(), out.len()))]
+fn push_letter(out: &mut Vec<u8>, c: u8) {
+    out.push(c);
+}
+
+// Appends the canonical encoding of one decimal digit, e.g. 4 => `one five`, 9 => `one ten`.
+// The previous letter has to be at least `ten`, so it is never subtracted.
+#[requires(digit <= 9)]
+#[requires(letter_value(one) > 0)]
+#[requires(letter_value(five) == 5 * letter_value(one))]
+#[requires(letter_value(ten) == 10 * letter_value(one))]
+#[requires(is_roman(out.as_slice()))]
+#[requires(out.len() == 0 || last_value(out.as_slice()) >= letter_value(ten))]
+#[ensures(is_roman(out.as_slice()))]
+#[ensures(out.len() <= old(out.len()) + 4)]
+#[ensures(out.len() == 0 || last_value(out.as_slice()) >= letter_value(one))]
+#[ensures(roman_value(out.as_slice()) ==
+    old(roman_value(out.as_slice())) + (digit * letter_value(one)) as i64)]
+fn push_roman_digit(out: &mut Vec<u8>, digit: u32, one: u8, five: u8, ten: u8) {
+    if digit == 9 {
+        push_letter(out, one);
+        push_letter(out, ten);
+    } else if digit == 4 {
+        push_letter(out, one);
+        push_letter(out, five);
+    } else {
+        let mut ones = digit;
+        if digit >= 5 {
+            push_letter(out, five);
+            ones -= 5;
+        }
+        let mut i = 0;
+        while i < ones {
+            body_invariant!(i < ones && ones <= 3);
+            body_invariant!(is_roman(out.as_slice()));
+            body_invariant!(out.len() <= old(out.len()) + 1 + i);
+            body_invariant!(out.len() == 0 || last_value(out.as_slice()) >= letter_value(one));
+            body_invariant!(roman_value(out.as_slice()) ==
+                old(roman_value(out.as_slice())) + ((digit - ones + i) * letter_value(one)) as i64);
+            push_letter(out, one);
+            i += 1;
+        }
+    }
+}
+
+#[requires(1 <= n && n <= 3999)]
+#[ensures(is_roman(result.as_slice()))]
+#[ensures(result.len() <= 15)]
+#[ensures(roman_value(result.as_slice()) == n as i64)]
+pub fn to_roman(n: u32) -> Vec<u8> {
+    let mut out = Vec::new();
+
+    let thousands = n / 1000;
+    let mut i = 0;
+    while i < thousands {
+        body_invariant!(i < thousands && thousands <= 3);
+        body_invariant!(out.len() == i);
+        body_invariant!(is_roman(out.as_slice()));
+        body_invariant!(out.len() == 0 || last_value(out.as_slice()) == 1000);
+        body_invariant!(roman_value(out.as_slice()) == (i * 1000) as i64);
+        push_letter(&mut out, b'M');
+        i += 1;
+    }
+
+    push_roman_digit(&mut out, n / 100 % 10, b'C', b'D', b'M');
+    push_roman_digit(&mut out, n / 10 % 10, b'X', b'L', b'C');
+    push_roman_digit(&mut out, n % 10, b'I', b'V', b'X');
+    out
+}
+
+// Partial inverse of `to_roman`: accepts any string of roman letters
+// (not only canonical ones) that is no longer than the longest canonical numeral.
+#[requires(s.len() <= 15)]
+#[ensures(is_roman(s) ==> result === Some(roman_value(s) as u32))]
+#[ensures(!is_roman(s) ==> result.is_none())]
+pub fn from_roman(s: &[u8]) -> Option<u32> {
+    let mut acc: i64 = 0;
+    let mut i = 0;
+
+    while i < s.len() {
+        body_invariant!(i < s.len());
+        body_invariant!(is_roman_upto(s, i));
+        body_invariant!(acc == roman_value_upto(s, i));
+        body_invariant!(0 <= acc && acc <= 1000 * i as i64);
+
+        if letter_value(s[i]) == 0 {
+            return None;
+        }
+        acc += letter_contribution(s, i + 1);
+        i += 1;
+    }
+
+    Some(acc as u32)
+}
+
+#[cfg(prusti)]
+mod prusti_tests {
+    use super::*;
+
+    fn _test_round_trip(n: u32) {
+        if 1 <= n && n <= 3999 {
+            let numeral = to_roman(n);
+            let back = from_roman(numeral.as_slice());
+            prusti_assert!(back === Some(n));
+        }
+    }
+
+    fn _test_values() {
+        prusti_assert!(roman_value(b"MCMXCIV") == 1994);
+        let four = from_roman(b"IIII");
+        prusti_assert!(four === Some(4)); // not canonical, but still accepted
+        let invalid = from_roman(b"IZ");
+        prusti_assert!(invalid.is_none());
+    }
+}
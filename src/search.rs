@@ -0,0 +1,93 @@
+use prusti_contracts::*;
+
+// Binary search on a sorted slice, once recursive and once as a loop, with the same contract.
+//
+// Both keep a range `lo..hi` such that `key` can't be anywhere outside of it. The recursive
+// version states this in its precondition, the loop in its invariant; otherwise the proofs
+// are the same. Comparing with the middle element excludes one half: sortedness says that
+// everything on that side is on the same side of `key`.
+
+predicate! {
+    pub fn sorted(s: &[i32]) -> bool {
+        forall(|i: usize, j: usize| (i <= j && j < s.len()) ==> s[i] <= s[j])
+    }
+}
+
+predicate! {
+    // `key` doesn't occur outside of `s[lo..hi]`
+    fn only_in_range(s: &[i32], key: i32, lo: usize, hi: usize) -> bool {
+        forall(|j: usize| (j < lo || (hi <= j && j < s.len())) ==> s[j] != key)
+    }
+}
+
+#[requires(sorted(s))]
+#[requires(lo <= hi && hi <= s.len())]
+#[requires(only_in_range(s, key, lo, hi))]
+#[ensures(match result {
+    Some(i) => i < s.len() && s[i] == key,
+    None => forall(|j: usize| j < s.len() ==> s[j] != key),
+})]
+fn search_range(s: &[i32], key: i32, lo: usize, hi: usize) -> Option<usize> {
+    if lo == hi {
+        return None;
+    }
+    // Written like this, `lo + hi` can't overflow
+    let mid = lo + (hi - lo) / 2;
+    if s[mid] == key {
+        Some(mid)
+    } else if s[mid] < key {
+        search_range(s, key, mid + 1, hi)
+    } else {
+        search_range(s, key, lo, mid)
+    }
+}
+
+#[requires(sorted(s))]
+#[ensures(match result {
+    Some(i) => i < s.len() && s[i] == key,
+    None => forall(|j: usize| j < s.len() ==> s[j] != key),
+})]
+pub fn binary_search_rec(s: &[i32], key: i32) -> Option<usize> {
+    search_range(s, key, 0, s.len())
+}
+
+#[requires(sorted(s))]
+#[ensures(match result {
+    Some(i) => i < s.len() && s[i] == key,
+    None => forall(|j: usize| j < s.len() ==> s[j] != key),
+})]
+pub fn binary_search(s: &[i32], key: i32) -> Option<usize> {
+    let mut lo = 0;
+    let mut hi = s.len();
+    while lo < hi {
+        body_invariant!(lo < hi && hi <= s.len());
+        body_invariant!(only_in_range(s, key, lo, hi));
+
+        let mid = lo + (hi - lo) / 2;
+        if s[mid] == key {
+            return Some(mid);
+        } else if s[mid] < key {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    None
+}
+
+#[cfg(prusti)]
+mod prusti_tests {
+    use super::*;
+
+    fn _test_binary_search() {
+        let s = [1, 3, 5, 7];
+        let found = binary_search(&s, 5);
+        prusti_assert!(found === Some(2));
+        let missing = binary_search(&s, 4);
+        prusti_assert!(missing.is_none());
+
+        let found_rec = binary_search_rec(&s, 7);
+        prusti_assert!(found_rec === Some(3));
+        // binary_search(&[3, 1], 1); // FAILS: the slice isn't sorted
+    }
+}
\ No newline at end of file
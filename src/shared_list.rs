@@ -0,0 +1,86 @@
+use prusti_contracts::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::list_generic_with_peek::List;
+
+// A list that several owners can read and change, like the "fourth list" built from
+// `Rc<RefCell<..>>`. Here the `RefCell` holds a whole verified `List`.
+//
+// Prusti can't look into a `RefCell`, so the two places that call `Rc::clone` and `borrow_mut`
+// are trusted shims. Any handle may change the list at any time, so what one handle saw says
+// nothing about what the next call sees: the shims promise nothing about the contents, and
+// `len`, `lookup` and `pop` aren't pure. Each call is a fresh observation. Within a call the list
+// is taken out, used through the `List` specs and put back, and the postconditions only state
+// what follows from that single borrow.
+pub struct SharedList<T: Copy> {
+    inner: Rc<RefCell<List<T>>>,
+}
+
+impl<T: Copy> SharedList<T> {
+    #[trusted]
+    pub fn new() -> Self {
+        SharedList { inner: Rc::new(RefCell::new(List::new())) }
+    }
+
+    // Another handle to the same list
+    #[trusted]
+    pub fn share(&self) -> Self {
+        SharedList { inner: Rc::clone(&self.inner) }
+    }
+
+    // `RefCell::borrow_mut` plus `mem::replace`. Panics if the list is borrowed elsewhere,
+    // which the shims never leave it. The list it returns is whatever the last call left behind.
+    #[trusted]
+    pub fn replace(&self, list: List<T>) -> List<T> {
+        std::mem::replace(&mut *self.inner.borrow_mut(), list)
+    }
+
+    pub fn len(&self) -> usize {
+        let list = self.replace(List::new());
+        let len = list.len();
+        self.replace(list);
+        len
+    }
+
+    // The element at `index` right now, if the list is long enough
+    pub fn lookup(&self, index: usize) -> Option<T> {
+        let list = self.replace(List::new());
+        let result = if index < list.len() { Some(*list.lookup(index)) } else { None };
+        self.replace(list);
+        result
+    }
+
+    // Returns the length right after the push, which includes `elem`
+    #[ensures(result > 0)]
+    pub fn push(&self, elem: T) -> usize {
+        let mut list = self.replace(List::new());
+        list.push(elem);
+        let len = list.len();
+        self.replace(list);
+        len
+    }
+
+    pub fn pop(&self) -> Option<T> {
+        let mut list = self.replace(List::new());
+        let result = list.try_pop();
+        self.replace(list);
+        result
+    }
+}
+
+#[cfg(prusti)]
+mod prusti_tests {
+    use super::*;
+
+    fn _test_shared_list() {
+        let list = SharedList::new();
+        let other = list.share();
+        let len = list.push(2);
+        prusti_assert!(len > 0);
+
+        other.push(1);
+        let head = list.pop();
+        // prusti_assert!(head === Some(1)); // FAILS: each call is a fresh observation
+    }
+}
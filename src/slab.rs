@@ -0,0 +1,260 @@
+use prusti_contracts::*;
+
+// A slab allocator: values live in slots of a `Vec`, and inserting one hands out the key of
+// its slot. Free slots are reused before the `Vec` grows.
+//
+// Clients reason with `is_live(key)` and `value(key)`. Every operation says what happens to the
+// key it is given and that all other keys keep their liveness and their values.
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Key(usize);
+
+impl Key {
+    #[pure]
+    pub fn index(&self) -> usize {
+        self.0
+    }
+}
+
+pub struct Slab<T: Copy> {
+    slots: Vec<Option<T>>,
+}
+
+impl<T: Copy> Slab<T> {
+    #[ensures(forall(|key: Key| !result.is_live(key)))]
+    pub fn new() -> Self {
+        Slab { slots: Vec::new() }
+    }
+
+    #[pure]
+    pub fn is_live(&self, key: Key) -> bool {
+        key.index() < self.slots.len() && self.slots[key.index()].is_some()
+    }
+
+    #[pure]
+    #[requires(self.is_live(key))]
+    pub fn value(&self, key: Key) -> T {
+        match self.slots[key.index()] {
+            Some(value) => value,
+            None => unreachable!(),
+        }
+    }
+
+    // The first free slot, or the end of the `Vec` if there is none
+    #[ensures(result <= self.slots.len())]
+    #[ensures(result < self.slots.len() ==> self.slots[result].is_none())]
+    fn free_slot(&self) -> usize {
+        let mut i = 0;
+        while i < self.slots.len() {
+            body_invariant!(i < self.slots.len());
+
+            if self.slots[i].is_none() {
+                return i;
+            }
+            i += 1;
+        }
+        i
+    }
+
+    #[ensures(!old(self.is_live(result)) && self.is_live(result))]
+    #[ensures(self.value(result) === value)]
+    #[ensures(forall(|key: Key| key.index() != result.index() ==> self.is_live(key) == old(self.is_live(key))))]
+    #[ensures(forall(|key: Key| (key.index() != result.index() && self.is_live(key)) ==> self.value(key) === old(self.value(key))))]
+    pub fn insert(&mut self, value: T) -> Key {
+        let index = self.free_slot();
+        if index < self.slots.len() {
+            self.slots[index] = Some(value);
+        } else {
+            self.slots.push(Some(value));
+        }
+        Key(index)
+    }
+
+    #[ensures(old(self.is_live(key)) ==> result === Some(self.value(key)))]
+    #[ensures(!old(self.is_live(key)) ==> result.is_none())]
+    pub fn get(&self, key: Key) -> Option<T> {
+        if key.index() < self.slots.len() {
+            self.slots[key.index()]
+        } else {
+            None
+        }
+    }
+
+    // Removing a dead key changes nothing
+    #[ensures(!self.is_live(key))]
+    #[ensures(old(self.is_live(key)) ==> result === Some(old(self.value(key))))]
+    #[ensures(!old(self.is_live(key)) ==> result.is_none())]
+    #[ensures(forall(|other: Key| other.index() != key.index() ==> self.is_live(other) == old(self.is_live(other))))]
+    #[ensures(forall(|other: Key| (other.index() != key.index() && self.is_live(other)) ==> self.value(other) === old(self.value(other))))]
+    pub fn remove(&mut self, key: Key) -> Option<T> {
+        if key.index() < self.slots.len() {
+            let value = self.slots[key.index()];
+            self.slots[key.index()] = None;
+            value
+        } else {
+            None
+        }
+    }
+}
+
+// A slab whose keys also carry the generation of their slot. Removing a value bumps the
+// generation, so keys handed out before are stale from then on, even after the slot is reused:
+// a stale key can never be used to reach a value inserted later (the ABA problem of plain slab
+// keys, where an old key silently refers to the new value).
+//
+// Generations only grow, and that is what the specs are built on: a key that is stale stays
+// stale, and a stale key is never live.
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct GenKey {
+    index: usize,
+    generation: u64,
+}
+
+impl GenKey {
+    #[pure]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    #[pure]
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+}
+
+#[invariant(self.values.len() == self.generations.len())]
+pub struct GenMap<T: Copy> {
+    values: Vec<Option<T>>,
+    generations: Vec<u64>,
+}
+
+impl<T: Copy> GenMap<T> {
+    #[ensures(forall(|key: GenKey| !result.is_live(key) && !result.is_stale(key)))]
+    pub fn new() -> Self {
+        GenMap { values: Vec::new(), generations: Vec::new() }
+    }
+
+    #[pure]
+    pub fn is_live(&self, key: GenKey) -> bool {
+        key.index() < self.values.len()
+            && self.generations[key.index()] == key.generation()
+            && self.values[key.index()].is_some()
+    }
+
+    // The slot has moved on to a later generation
+    #[pure]
+    pub fn is_stale(&self, key: GenKey) -> bool {
+        key.index() < self.generations.len() && key.generation() < self.generations[key.index()]
+    }
+
+    #[pure]
+    #[requires(self.is_live(key))]
+    pub fn value(&self, key: GenKey) -> T {
+        match self.values[key.index()] {
+            Some(value) => value,
+            None => unreachable!(),
+        }
+    }
+
+    #[ensures(result <= self.values.len())]
+    #[ensures(result < self.values.len() ==> self.values[result].is_none())]
+    fn free_slot(&self) -> usize {
+        let mut i = 0;
+        while i < self.values.len() {
+            body_invariant!(i < self.values.len());
+
+            if self.values[i].is_none() {
+                return i;
+            }
+            i += 1;
+        }
+        i
+    }
+
+    // The new key is live, and no key that was stale before can refer to it
+    #[ensures(self.is_live(result) && self.value(result) === value)]
+    #[ensures(forall(|key: GenKey| old(self.is_stale(key)) ==> self.is_stale(key)))]
+    #[ensures(forall(|key: GenKey| self.is_stale(key) ==> !self.is_live(key)))]
+    #[ensures(forall(|key: GenKey| key.index() != result.index() ==> self.is_live(key) == old(self.is_live(key))))]
+    #[ensures(forall(|key: GenKey| (key.index() != result.index() && self.is_live(key)) ==>
+        self.value(key) === old(self.value(key))))]
+    pub fn insert(&mut self, value: T) -> GenKey {
+        let index = self.free_slot();
+        if index < self.values.len() {
+            self.values[index] = Some(value);
+            GenKey { index, generation: self.generations[index] }
+        } else {
+            self.values.push(Some(value));
+            self.generations.push(0);
+            GenKey { index, generation: 0 }
+        }
+    }
+
+    #[ensures(old(self.is_live(key)) ==> result === Some(self.value(key)))]
+    #[ensures(!old(self.is_live(key)) ==> result.is_none())]
+    pub fn get(&self, key: GenKey) -> Option<T> {
+        if key.index() < self.values.len() && self.generations[key.index()] == key.generation() {
+            self.values[key.index()]
+        } else {
+            None
+        }
+    }
+
+    // Only the owner of the current generation can remove the value. Its key is stale afterwards.
+    #[ensures(!self.is_live(key))]
+    #[ensures(old(self.is_live(key)) ==> result === Some(old(self.value(key))) && self.is_stale(key))]
+    #[ensures(!old(self.is_live(key)) ==> result.is_none())]
+    #[ensures(forall(|other: GenKey| old(self.is_stale(other)) ==> self.is_stale(other)))]
+    #[ensures(forall(|other: GenKey| other.index() != key.index() ==> self.is_live(other) == old(self.is_live(other))))]
+    #[ensures(forall(|other: GenKey| (other.index() != key.index() && self.is_live(other)) ==>
+        self.value(other) === old(self.value(other))))]
+    pub fn remove(&mut self, key: GenKey) -> Option<T> {
+        if key.index() < self.values.len()
+            && self.generations[key.index()] == key.generation()
+            && self.values[key.index()].is_some()
+        {
+            let value = self.values[key.index()];
+            self.values[key.index()] = None;
+            self.generations[key.index()] += 1;
+            value
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(prusti)]
+mod prusti_tests {
+    use super::*;
+
+    fn _test_slab() {
+        let mut slab = Slab::new();
+        let a = slab.insert(10);
+        let b = slab.insert(20);
+        prusti_assert!(a.index() != b.index());
+        let x = slab.get(a);
+        prusti_assert!(x === Some(10));
+
+        let removed = slab.remove(a);
+        prusti_assert!(removed === Some(10));
+        prusti_assert!(!slab.is_live(a) && slab.is_live(b));
+        let gone = slab.get(a);
+        prusti_assert!(gone.is_none());
+        let y = slab.get(b);
+        prusti_assert!(y === Some(20));
+    }
+
+    fn _test_gen_map() {
+        let mut map = GenMap::new();
+        let old_key = map.insert(10);
+        map.remove(old_key);
+        // The slot is reused, but under a new generation
+        let new_key = map.insert(20);
+        prusti_assert!(map.is_stale(old_key) && !map.is_live(old_key));
+        let stale = map.get(old_key);
+        prusti_assert!(stale.is_none());
+        let fresh = map.get(new_key);
+        prusti_assert!(fresh === Some(20));
+    }
+}
\ No newline at end of file
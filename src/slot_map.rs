@@ -0,0 +1,109 @@
+use prusti_contracts::*;
+
+// A slot map: the values are stored densely (without gaps, so iterating over them is fast),
+// and keys are slots in a sparse table that point into the dense storage. Removing a value
+// moves the last one into the gap, which means updating the slot that pointed to it.
+//
+// The type invariant is the consistency of the two directions: the slot recorded for a dense
+// position points back to that position, and the dense position of a live slot points back
+// to the slot. Clients only see `is_live(slot)` and `value(slot)`, which don't change when
+// values move around in the dense storage.
+#[invariant(self.values.len() == self.dense_to_slot.len())]
+#[invariant(self.live.len() == self.slot_to_dense.len())]
+#[invariant(forall(|i: usize| i < self.dense_to_slot.len() ==>
+    self.dense_to_slot[i] < self.live.len()
+    && self.live[self.dense_to_slot[i]]
+    && self.slot_to_dense[self.dense_to_slot[i]] == i))]
+#[invariant(forall(|s: usize| (s < self.live.len() && self.live[s]) ==>
+    self.slot_to_dense[s] < self.dense_to_slot.len()
+    && self.dense_to_slot[self.slot_to_dense[s]] == s))]
+pub struct SlotMap<T: Copy> {
+    values: Vec<T>,
+    dense_to_slot: Vec<usize>,
+    slot_to_dense: Vec<usize>,
+    live: Vec<bool>,
+}
+
+impl<T: Copy> SlotMap<T> {
+    #[ensures(result.len() == 0)]
+    #[ensures(forall(|slot: usize| !result.is_live(slot)))]
+    pub fn new() -> Self {
+        SlotMap { values: Vec::new(), dense_to_slot: Vec::new(), slot_to_dense: Vec::new(), live: Vec::new() }
+    }
+
+    // Number of values, which is also the size of the dense storage
+    #[pure]
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    #[pure]
+    pub fn is_live(&self, slot: usize) -> bool {
+        slot < self.live.len() && self.live[slot]
+    }
+
+    #[pure]
+    #[requires(self.is_live(slot))]
+    pub fn value(&self, slot: usize) -> T {
+        self.values[self.slot_to_dense[slot]]
+    }
+
+    // Always uses a new slot, so a removed slot never comes back to life
+    #[ensures(!old(self.is_live(result)) && self.is_live(result))]
+    #[ensures(self.value(result) === value)]
+    #[ensures(self.len() == old(self.len()) + 1)]
+    #[ensures(forall(|slot: usize| slot != result ==> self.is_live(slot) == old(self.is_live(slot))))]
+    #[ensures(forall(|slot: usize| (slot != result && self.is_live(slot)) ==> self.value(slot) === old(self.value(slot))))]
+    pub fn insert(&mut self, value: T) -> usize {
+        let slot = self.live.len();
+        self.live.push(true);
+        self.slot_to_dense.push(self.values.len());
+        self.dense_to_slot.push(slot);
+        self.values.push(value);
+        slot
+    }
+
+    #[ensures(!self.is_live(slot))]
+    #[ensures(old(self.is_live(slot)) ==> result === Some(old(self.value(slot))) && self.len() == old(self.len()) - 1)]
+    #[ensures(!old(self.is_live(slot)) ==> result.is_none() && self.len() == old(self.len()))]
+    #[ensures(forall(|other: usize| other != slot ==> self.is_live(other) == old(self.is_live(other))))]
+    #[ensures(forall(|other: usize| (other != slot && self.is_live(other)) ==> self.value(other) === old(self.value(other))))]
+    pub fn remove(&mut self, slot: usize) -> Option<T> {
+        if slot >= self.live.len() || !self.live[slot] {
+            return None;
+        }
+        // The last value moves into the gap, and its slot is redirected there. If the removed
+        // value is the last one, this is a no-op before the `pop`s.
+        let gap = self.slot_to_dense[slot];
+        let last = self.values.len() - 1;
+        let value = self.values[gap];
+        let moved = self.dense_to_slot[last];
+        self.values[gap] = self.values[last];
+        self.dense_to_slot[gap] = moved;
+        self.slot_to_dense[moved] = gap;
+        self.values.pop();
+        self.dense_to_slot.pop();
+        self.live[slot] = false;
+        Some(value)
+    }
+}
+
+#[cfg(prusti)]
+mod prusti_tests {
+    use super::*;
+
+    fn _test_slot_map() {
+        let mut map = SlotMap::new();
+        let a = map.insert(1);
+        let b = map.insert(2);
+        let c = map.insert(3);
+        // `c` is the last dense value and moves into the place of `a`
+        let removed = map.remove(a);
+        prusti_assert!(removed === Some(1));
+        prusti_assert!(map.len() == 2 && !map.is_live(a));
+        prusti_assert!(map.value(b) == 2 && map.value(c) == 3);
+
+        let again = map.remove(a);
+        prusti_assert!(again.is_none());
+    }
+}
\ No newline at end of file
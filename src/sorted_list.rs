@@ -0,0 +1,220 @@
+use prusti_contracts::*;
+
+use crate::list_generic_with_peek::List;
+use crate::pure_ord::PureOrd;
+
+predicate! {
+    // Every element is `leq` all elements after it.
+    // Comparing all pairs (instead of neighbours) means no induction is needed to use it.
+    pub fn is_sorted<T: PureOrd>(list: &List<T>) -> bool {
+        forall(|i: usize, j: usize| (i <= j && j < list.len()) ==>
+            list.lookup(i).leq(list.lookup(j)))
+    }
+}
+
+predicate! {
+    // Every element of `list` is either `elem` or an element of `prev`
+    fn elements_from<T>(list: &List<T>, prev: &List<T>, elem: &T) -> bool {
+        forall(|i: usize| i < list.len() ==>
+            list.lookup(i) === elem
+            || exists(|j: usize| j < prev.len() && list.lookup(i) === prev.lookup(j)))
+    }
+}
+
+predicate! {
+    // Every element of `prev` is still in `list`
+    fn elements_kept<T>(list: &List<T>, prev: &List<T>) -> bool {
+        forall(|j: usize| j < prev.len() ==>
+            exists(|i: usize| i < list.len() && list.lookup(i) === prev.lookup(j)))
+    }
+}
+
+#[requires(is_sorted(list))]
+#[ensures(is_sorted(list))]
+#[ensures(list.len() == old(list.len()) + 1)]
+#[ensures(elements_from(list, &old(snap(list)), &elem))]
+#[ensures(elements_kept(list, &old(snap(list))))]
+// The index where `elem` ended up, which is the witness for `SortedList::insert`
+#[ensures(result < list.len() && snap(list.lookup(result)) === elem)]
+// Exactly one occurrence of `elem` is added, which `elements_from`/`elements_kept` can't express
+#[refine_spec(where T: Copy + PartialEq, [
+    ensures(forall(|v: T| list.count(&v) == old(list.count(&v)) + if elem == v { 1 } else { 0 }))
+])]
+fn insert_into<T: PureOrd>(list: &mut List<T>, elem: T) -> usize {
+    if list.is_empty() || elem.leq(list.lookup(0)) {
+        // `elem` is below the head, and the head is below everything else
+        prusti_assert!(forall(|j: usize| j < list.len() ==>
+            elem.transitive(list.lookup(0), list.lookup(j))));
+        list.push(elem);
+        0
+    } else {
+        let head = list.pop();
+        // `elem` is not below the head, so the head is below `elem`
+        prusti_assert!(elem.total(&head));
+        let index = insert_into(list, elem);
+        list.push(head);
+        index + 1
+    }
+}
+
+#[invariant(is_sorted(&self.list))]
+pub struct SortedList<T: PureOrd> {
+    list: List<T>,
+}
+
+impl<T: PureOrd> SortedList<T> {
+    #[ensures(result.len() == 0)]
+    pub fn new() -> Self {
+        SortedList { list: List::new() }
+    }
+
+    #[pure]
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    #[pure]
+    #[requires(index < self.len())]
+    pub fn lookup(&self, index: usize) -> &T {
+        self.list.lookup(index)
+    }
+
+    #[ensures(self.len() == old(self.len()) + 1)]
+    #[ensures(exists(|i: usize| i < self.len() && snap(self.lookup(i)) === elem))]
+    #[ensures(forall(|i: usize| i < self.len() ==>
+        snap(self.lookup(i)) === elem
+        || exists(|j: usize| j < old(self.len()) && self.lookup(i) === old(self.lookup(j)))))]
+    #[ensures(forall(|j: usize| j < old(self.len()) ==>
+        exists(|i: usize| i < self.len() && self.lookup(i) === old(self.lookup(j)))))]
+    pub fn insert(&mut self, elem: T) {
+        // The returned index is the witness for the `exists`
+        insert_into(&mut self.list, elem);
+    }
+
+    // The first element is the smallest one
+    #[pure]
+    #[requires(self.len() > 0)]
+    #[ensures(forall(|i: usize| i < self.len() ==> result.leq(self.lookup(i))))]
+    pub fn min(&self) -> &T {
+        self.list.lookup(0)
+    }
+
+    #[requires(self.len() > 0)]
+    #[ensures(self.len() == old(self.len()) - 1)]
+    #[ensures(result === old(snap(self.lookup(0))))]
+    #[ensures(forall(|i: usize| i < self.len() ==> result.leq(self.lookup(i))))]
+    #[refine_spec(where T: Copy + PartialEq, [
+        ensures(forall(|v: T| old(self.count(&v)) == self.count(&v) + if result == v { 1 } else { 0 }))
+    ])]
+    pub fn pop_min(&mut self) -> T {
+        self.list.pop()
+    }
+
+    #[ensures(result == exists(|i: usize| i < self.len() && self.lookup(i).equiv(elem)))]
+    pub fn contains(&self, elem: &T) -> bool {
+        let mut i = 0;
+        while i < self.len() {
+            body_invariant!(i < self.len());
+            body_invariant!(forall(|j: usize| j < i ==> !self.lookup(j).equiv(elem)));
+
+            let current = self.lookup(i);
+            if current.equiv(elem) {
+                return true;
+            }
+            if !current.leq(elem) {
+                // Everything after `current` is at least `current`, so by transitivity
+                // none of it can be below `elem` either
+                prusti_assert!(forall(|j: usize| (i <= j && j < self.len()) ==>
+                    !self.lookup(j).leq(elem) || current.transitive(self.lookup(j), elem)));
+                return false;
+            }
+            i += 1;
+        }
+        false
+    }
+}
+
+impl<T: PureOrd + PartialEq> SortedList<T> {
+    #[pure]
+    #[ensures(result <= self.len())]
+    pub fn count(&self, value: &T) -> usize {
+        self.list.count(value)
+    }
+
+    #[ensures(is_sorted(&result))]
+    #[ensures(result.len() == self.len())]
+    #[ensures(forall(|v: T| result.count(&v) == self.count(&v)))]
+    pub fn into_list(self) -> List<T> {
+        self.list
+    }
+}
+
+impl SortedList<i32> {
+    // `insert` with the full functional spec: the list stays sorted and is a permutation
+    // of the old elements plus `elem`, stated through the occurrence counts
+    #[ensures(self.len() == old(self.len()) + 1)]
+    #[ensures(forall(|i: usize, j: usize| (i <= j && j < self.len()) ==>
+        *self.lookup(i) <= *self.lookup(j)))]
+    #[ensures(self.count(&elem) == old(self.count(&elem)) + 1)]
+    #[ensures(forall(|v: i32| v != elem ==> self.count(&v) == old(self.count(&v))))]
+    pub fn insert_sorted(&mut self, elem: i32) {
+        insert_into(&mut self.list, elem);
+    }
+}
+
+impl List<i32> {
+    // Insertion sort: the elements are moved one by one into a `SortedList`,
+    // whose invariant takes care of sortedness. The counts show nothing got lost on the way.
+    #[ensures(is_sorted(self))]
+    #[ensures(self.len() == old(self.len()))]
+    #[ensures(forall(|v: i32| self.count(&v) == old(self.count(&v))))]
+    pub fn sort(&mut self) {
+        let mut sorted = SortedList::new();
+        while !self.is_empty() {
+            body_invariant!(sorted.len() + self.len() == old(self.len()));
+            body_invariant!(forall(|v: i32| sorted.count(&v) + self.count(&v) == old(self.count(&v))));
+
+            let elem = self.pop();
+            sorted.insert_sorted(elem);
+        }
+        *self = sorted.into_list();
+    }
+}
+
+#[cfg(prusti)]
+mod prusti_tests {
+    use super::*;
+
+    fn _test_sorted_list() {
+        let mut list = SortedList::new();
+        list.insert(5);
+        list.insert(1);
+        list.insert(3);
+        prusti_assert!(list.len() == 3);
+        prusti_assert!(*list.min() <= 3 && *list.min() <= 5);
+
+        let x = list.pop_min();
+        prusti_assert!(list.len() == 2);
+        prusti_assert!(x <= *list.lookup(0) && x <= *list.lookup(1));
+    }
+
+    fn _test_insert_sorted() {
+        let mut list = SortedList::new();
+        list.insert_sorted(4);
+        list.insert_sorted(2);
+        list.insert_sorted(4);
+        prusti_assert!(list.count(&4) == 2 && list.count(&2) == 1 && list.count(&3) == 0);
+    }
+
+    fn _test_sort() {
+        let mut list = List::new();
+        list.push(2);
+        list.push(7);
+        list.push(2);
+        list.push(-1);
+        list.sort();
+        prusti_assert!(is_sorted(&list));
+        prusti_assert!(list.len() == 4);
+        prusti_assert!(list.count(&2) == 2 && list.count(&7) == 1 && list.count(&-1) == 1);
+    }
+}
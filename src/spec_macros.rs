@@ -0,0 +1,30 @@
+// Macros that write recurring spec clauses.
+//
+// Spec attributes are expanded by Prusti after `macro_rules!`, so a macro can't appear inside
+// an `#[ensures(..)]`, but it can emit the attribute in front of a function.
+
+// The frame condition "every element except the ones at the given indices is unchanged".
+// `|k| elem` is the element at index `k` (it's compared with `===`, so it has to be usable
+// with `old`) and `len` bounds the indices. Wraps the function the clause belongs to:
+//
+//     ensures_unchanged! {
+//         |k| v[k], v.len(), except i, j;
+//         #[requires(i < v.len() && j < v.len())]
+//         pub fn swap(v: &mut Vec<i32>, i: usize, j: usize) { .. }
+//     }
+//
+// With `after_expiry(..)` in front the clause is a pledge instead of a postcondition, for
+// functions returning a mutable reference into `self`. A function has only one pledge, so
+// the rest of it goes into the parentheses.
+macro_rules! ensures_unchanged {
+    (|$k:ident| $elem:expr, $len:expr, except $($index:expr),+; $($item:tt)*) => {
+        #[ensures(forall(|$k: usize| ($k < $len $(&& $k != $index)+) ==> $elem === old($elem)))]
+        $($item)*
+    };
+    (after_expiry($($also:tt)+) |$k:ident| $elem:expr, $len:expr, except $($index:expr),+; $($item:tt)*) => {
+        #[after_expiry(($($also)+) && forall(|$k: usize| ($k < $len $(&& $k != $index)+) ==> $elem === old($elem)))]
+        $($item)*
+    };
+}
+
+pub(crate) use ensures_unchanged;
\ No newline at end of file
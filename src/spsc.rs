@@ -0,0 +1,167 @@
+use prusti_contracts::*;
+use std::cell::UnsafeCell;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+// A bounded single-producer single-consumer ring queue, split into a `Producer` and a
+// `Consumer` half that share the buffer and can live on different threads.
+//
+// `head` and `tail` count all reads and writes so far; the slot of an index is `index % N`
+// and the queue holds `tail - head` elements. Each half owns the index it writes and keeps its
+// own copy of it, so its specs can talk about it. The other index can change at any time, so a
+// half only ever loads it, and all it learns is what the protocol guarantees: the consumer
+// never gets ahead of the producer, and the producer never gets more than `N` ahead.
+//
+// Prusti can't reason about atomics, `Arc` or `UnsafeCell`, so the operations on the shared
+// state are trusted, take `&self` and are described by what they load. What is verified is
+// the protocol on top of them: every slot index is in bounds, the producer only writes a slot
+// that no queued element is in, and the consumer only reads slots the producer has published.
+
+struct Shared<T, const N: usize> {
+    buf: [UnsafeCell<T>; N],
+    head: AtomicUsize,
+    tail: AtomicUsize,
+}
+
+// The producer writes a slot only while it is outside `head..tail`, the consumer reads it
+// only while it is inside, so the two never access the same slot at the same time
+unsafe impl<T: Send, const N: usize> Sync for Shared<T, N> {}
+
+#[invariant(N > 0)]
+pub struct Producer<T, const N: usize> {
+    shared: Arc<Shared<T, N>>,
+    tail: usize,
+}
+
+#[invariant(N > 0)]
+pub struct Consumer<T, const N: usize> {
+    shared: Arc<Shared<T, N>>,
+    head: usize,
+}
+
+#[trusted]
+#[requires(N > 0)]
+#[ensures(result.0.tail() == 0 && result.1.head() == 0)]
+pub fn channel<T: Copy + Default, const N: usize>() -> (Producer<T, N>, Consumer<T, N>) {
+    let shared = Arc::new(Shared {
+        buf: std::array::from_fn(|_| UnsafeCell::new(T::default())),
+        head: AtomicUsize::new(0),
+        tail: AtomicUsize::new(0),
+    });
+    (Producer { shared: Arc::clone(&shared), tail: 0 }, Consumer { shared, head: 0 })
+}
+
+impl<T: Copy, const N: usize> Producer<T, N> {
+    #[pure]
+    pub fn tail(&self) -> usize {
+        self.tail
+    }
+
+    // The consumer's index. It may have moved on since, but never past `tail`.
+    #[trusted]
+    #[ensures(result <= self.tail() && self.tail() - result <= N)]
+    fn load_head(&self) -> usize {
+        self.shared.head.load(Ordering::Acquire)
+    }
+
+    #[trusted]
+    #[requires(slot < N)]
+    fn write(&self, slot: usize, value: T) {
+        // SAFETY: `push` only writes the slot of `tail`, which the consumer doesn't read
+        unsafe { *self.shared.buf[slot].get() = value }
+    }
+
+    // Hands the slots before `tail` to the consumer
+    #[trusted]
+    fn store_tail(&self) {
+        self.shared.tail.store(self.tail, Ordering::Release)
+    }
+
+    // A full queue is reported instead of overwritten. The queued elements are the ones
+    // between `head` and `tail`, and none of them is in the slot of `tail`.
+    #[ensures(result ==> self.tail() == old(self.tail()) + 1)]
+    #[ensures(!result ==> self.tail() == old(self.tail()))]
+    pub fn push(&mut self, value: T) -> bool {
+        let head = self.load_head();
+        if self.tail - head == N {
+            return false;
+        }
+        prusti_assert!(forall(|i: usize| i < self.tail - head ==> lemma_distinct_slots(head, self.tail, i, N)));
+        self.write(self.tail % N, value);
+        self.tail += 1;
+        self.store_tail();
+        true
+    }
+}
+
+impl<T: Copy, const N: usize> Consumer<T, N> {
+    #[pure]
+    pub fn head(&self) -> usize {
+        self.head
+    }
+
+    // The producer's index. It may have moved on since, but never more than `N` past `head`.
+    #[trusted]
+    #[ensures(self.head() <= result && result - self.head() <= N)]
+    fn load_tail(&self) -> usize {
+        self.shared.tail.load(Ordering::Acquire)
+    }
+
+    #[trusted]
+    #[requires(slot < N)]
+    fn read(&self, slot: usize) -> T {
+        // SAFETY: `pop` only reads the slot of `head`, which the producer has published
+        // and doesn't write again before `head` moves on
+        unsafe { *self.shared.buf[slot].get() }
+    }
+
+    // Hands the slots before `head` back to the producer
+    #[trusted]
+    fn store_head(&self) {
+        self.shared.head.store(self.head, Ordering::Release)
+    }
+
+    // `None` if the producer hasn't published anything new
+    #[ensures(result.is_some() ==> self.head() == old(self.head()) + 1)]
+    #[ensures(result.is_none() ==> self.head() == old(self.head()))]
+    pub fn pop(&mut self) -> Option<T> {
+        let tail = self.load_tail();
+        if self.head == tail {
+            return None;
+        }
+        let value = self.read(self.head % N);
+        self.head += 1;
+        self.store_head();
+        Some(value)
+    }
+}
+
+// Lemma: fewer than `n` indices apart, two indices never share a slot. `tail` is
+// `head + i + d` with `0 < d < n`, and the slots of `a` and `a + d` are only the same if
+// `n` divides `d`.
+#[pure]
+#[requires(n > 0 && head <= tail && tail - head < n && i < tail - head)]
+#[ensures((head + i) % n != tail % n)]
+// The parameters only appear in the spec
+#[allow(unused_variables)]
+fn lemma_distinct_slots(head: usize, tail: usize, i: usize, n: usize) -> bool {
+    true
+}
+
+#[cfg(prusti)]
+mod prusti_tests {
+    use super::*;
+
+    fn _test_spsc() {
+        let (mut producer, mut consumer) = channel::<i32, 2>();
+        // The consumer is never ahead, so the first two pushes find free slots
+        let a = producer.push(1);
+        let b = producer.push(2);
+        prusti_assert!(a && b && producer.tail() == 2);
+        // let c = producer.push(3); prusti_assert!(!c); // FAILS: the consumer may have read a slot already
+
+        let first = consumer.pop();
+        prusti_assert!(first.is_none() || consumer.head() == 1);
+        // let _ = channel::<i32, 0>(); // FAILS: a queue needs at least one slot
+    }
+}
@@ -0,0 +1,157 @@
+use prusti_contracts::*;
+
+use crate::list_generic_with_peek::List;
+
+// Last-in-first-out stack. `peek_at(0)` is the top of the stack, `peek_at(1)` the element below it, ...
+pub trait Stack<T> {
+    #[pure]
+    fn len(&self) -> usize;
+
+    #[pure]
+    fn is_full(&self) -> bool;
+
+    #[pure]
+    #[requires(depth < self.len())]
+    fn peek_at(&self, depth: usize) -> &T;
+
+    #[requires(!self.is_full())]
+    #[ensures(self.len() == old(self.len()) + 1)]
+    #[ensures(snap(self.peek_at(0)) === elem)]
+    #[ensures(forall(|i: usize| i < old(self.len()) ==>
+        old(self.peek_at(i)) === self.peek_at(i + 1)))]
+    fn push(&mut self, elem: T);
+
+    #[requires(self.len() > 0)]
+    #[ensures(self.len() == old(self.len()) - 1)]
+    #[ensures(!self.is_full())]
+    #[ensures(result === old(snap(self.peek_at(0))))]
+    #[ensures(forall(|i: usize| i < self.len() ==>
+        self.peek_at(i) === old(self.peek_at(i + 1))))]
+    fn pop(&mut self) -> T;
+}
+
+// An unbounded stack: the linked list is never full
+#[refine_trait_spec]
+impl<T> Stack<T> for List<T> {
+    #[pure]
+    fn len(&self) -> usize {
+        List::len(self)
+    }
+
+    #[pure]
+    fn is_full(&self) -> bool {
+        false
+    }
+
+    #[pure]
+    fn peek_at(&self, depth: usize) -> &T {
+        self.lookup(depth)
+    }
+
+    fn push(&mut self, elem: T) {
+        List::push(self, elem)
+    }
+
+    fn pop(&mut self) -> T {
+        List::pop(self)
+    }
+}
+
+pub const CAPACITY: usize = 16;
+
+// A stack with a fixed capacity that lives entirely on the stack (no heap allocation)
+#[invariant(self.len <= CAPACITY)]
+pub struct BoundedStack {
+    elems: [i32; CAPACITY],
+    len: usize,
+}
+
+impl BoundedStack {
+    #[ensures(result.len() == 0)]
+    pub fn new() -> Self {
+        BoundedStack { elems: [0; CAPACITY], len: 0 }
+    }
+}
+
+#[refine_trait_spec]
+impl Stack<i32> for BoundedStack {
+    #[pure]
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    #[pure]
+    fn is_full(&self) -> bool {
+        self.len == CAPACITY
+    }
+
+    #[pure]
+    fn peek_at(&self, depth: usize) -> &i32 {
+        &self.elems[self.len - 1 - depth]
+    }
+
+    fn push(&mut self, elem: i32) {
+        self.elems[self.len] = elem;
+        self.len += 1;
+    }
+
+    fn pop(&mut self) -> i32 {
+        self.len -= 1;
+        self.elems[self.len]
+    }
+}
+
+// The clients below are verified only against the `Stack` contracts,
+// so they are correct for every implementation at once.
+
+// Pushing and immediately popping gives back the element and leaves the stack as it was
+#[requires(!stack.is_full())]
+#[ensures(result === elem)]
+#[ensures(stack.len() == old(stack.len()))]
+#[ensures(forall(|i: usize| i < stack.len() ==>
+    stack.peek_at(i) === old(stack.peek_at(i))))]
+pub fn push_then_pop<T, S: Stack<T>>(stack: &mut S, elem: T) -> T {
+    stack.push(elem);
+    stack.pop()
+}
+
+// Swaps the top of the stack for `elem` and returns the old top
+#[requires(stack.len() > 0)]
+#[ensures(result === old(snap(stack.peek_at(0))))]
+#[ensures(snap(stack.peek_at(0)) === elem)]
+#[ensures(stack.len() == old(stack.len()))]
+#[ensures(forall(|i: usize| 1 <= i && i < stack.len() ==>
+    stack.peek_at(i) === old(stack.peek_at(i))))]
+pub fn replace_top<T, S: Stack<T>>(stack: &mut S, elem: T) -> T {
+    let top = stack.pop();
+    stack.push(elem);
+    top
+}
+
+#[cfg(prusti)]
+mod prusti_tests {
+    use super::*;
+
+    fn _test_bounded_stack() {
+        let mut stack = BoundedStack::new();
+        stack.push(1);
+        stack.push(2);
+        prusti_assert!(stack.len() == 2);
+        prusti_assert!(*stack.peek_at(0) == 2);
+
+        let old_top = replace_top(&mut stack, 3);
+        prusti_assert!(old_top == 2);
+        prusti_assert!(*stack.peek_at(0) == 3 && *stack.peek_at(1) == 1);
+
+        let x = push_then_pop(&mut stack, 4);
+        prusti_assert!(x == 4 && stack.len() == 2);
+    }
+
+    fn _test_list_stack() {
+        let mut list = List::new();
+        Stack::push(&mut list, 8);
+        let x = push_then_pop(&mut list, 16);
+        prusti_assert!(x == 16);
+        prusti_assert!(*Stack::peek_at(&list, 0) == 8);
+    }
+}
@@ -0,0 +1,257 @@
+use prusti_contracts::*;
+
+// Extern specs for std items that are shared by several modules.
+// Prusti rejects duplicate extern specs for the same function, so they live in one place.
+// (The `Option` and `mem::replace` specs are in `list_generic_with_peek`.)
+
+#[extern_spec]
+impl<T> std::vec::Vec<T> {
+    #[ensures(result.len() == 0)]
+    pub fn new() -> Vec<T>;
+
+    #[pure]
+    pub fn len(&self) -> usize;
+
+    #[pure]
+    #[ensures(result == (self.len() == 0))]
+    pub fn is_empty(&self) -> bool;
+
+    #[ensures(self.len() == old(self.len()) + 1)]
+    #[ensures(snap(&self[old(self.len())]) === value)]
+    #[ensures(forall(|i: usize| i < old(self.len()) ==>
+        snap(&self[i]) === old(snap(&self[i]))))]
+    pub fn push(&mut self, value: T);
+
+    #[ensures(old(self.len()) == 0 ==> result.is_none() && self.len() == 0)]
+    #[ensures(old(self.len()) > 0 ==>
+        self.len() == old(self.len()) - 1
+        && result === Some(old(snap(&self[self.len() - 1]))))]
+    #[ensures(forall(|i: usize| i < self.len() ==>
+        snap(&self[i]) === old(snap(&self[i]))))]
+    pub fn pop(&mut self) -> Option<T>;
+
+    // Lets specs written over slices talk about the contents of a `Vec`
+    #[pure]
+    #[ensures(result.len() == self.len())]
+    #[ensures(forall(|i: usize| i < self.len() ==> snap(&result[i]) === snap(&self[i])))]
+    pub fn as_slice(&self) -> &[T];
+}
+
+// Strings are only described by their length in bytes: Prusti can't look at their contents
+#[extern_spec]
+impl str {
+    #[pure]
+    pub const fn len(&self) -> usize;
+}
+
+#[extern_spec]
+impl String {
+    #[ensures(result.len() == 0)]
+    pub fn new() -> String;
+
+    #[pure]
+    pub fn len(&self) -> usize;
+
+    #[pure]
+    #[ensures(result == (self.len() == 0))]
+    pub fn is_empty(&self) -> bool;
+
+    #[ensures(self.len() == old(self.len()) + string.len())]
+    pub fn push_str(&mut self, string: &str);
+
+    #[ensures(self.len() == 0)]
+    pub fn clear(&mut self);
+
+    #[pure]
+    #[ensures(result.len() == self.len())]
+    pub fn as_str(&self) -> &str;
+}
+
+#[extern_spec]
+impl<T, E> std::result::Result<T, E> {
+    #[pure]
+    #[ensures(result == matches!(self, Ok(_)))]
+    pub const fn is_ok(&self) -> bool;
+
+    #[pure]
+    #[ensures(result == matches!(self, Err(_)))]
+    pub const fn is_err(&self) -> bool;
+
+    #[ensures(old(self.is_ok()) == result.is_some())]
+    pub fn ok(self) -> Option<T>;
+}
+
+#[extern_spec]
+impl<T> std::ops::Index<usize> for Vec<T> {
+    #[pure]
+    #[requires(index < self.len())]
+    fn index(&self, index: usize) -> &T;
+}
+
+#[extern_spec]
+impl<T> std::ops::IndexMut<usize> for Vec<T> {
+    #[requires(index < self.len())]
+    #[ensures(snap(result) === old(snap(&self[index])))]
+    #[after_expiry(
+        self.len() == old(self.len())
+        && snap(&self[index]) === before_expiry(snap(result))
+        && forall(|i: usize| (i < self.len() && i != index) ==>
+            snap(&self[i]) === old(snap(&self[i])))
+    )]
+    fn index_mut(&mut self, index: usize) -> &mut T;
+}
+
+// Operator syntax (`a[i]`) on any type is only usable in specs if `index` is pure
+#[extern_spec(std::ops)]
+trait Index<Idx> {
+    #[pure]
+    fn index(&self, index: Idx) -> &Self::Output;
+}
+
+// Euclidean division: the remainder is never negative. `i64::MIN / -1` overflows.
+#[extern_spec]
+impl i64 {
+    #[pure]
+    #[requires(rhs != 0 && !(self == i64::MIN && rhs == -1))]
+    #[ensures(self == result * rhs + self.rem_euclid(rhs))]
+    pub fn div_euclid(self, rhs: i64) -> i64;
+
+    #[pure]
+    #[requires(rhs != 0 && !(self == i64::MIN && rhs == -1))]
+    #[ensures(0 <= result)]
+    #[ensures(rhs > 0 ==> result < rhs)]
+    #[ensures(rhs < 0 ==> result + rhs < 0)]
+    pub fn rem_euclid(self, rhs: i64) -> i64;
+}
+
+// Two's complement addition: the sum wraps around by `2^32` if it leaves the `i32` range
+#[extern_spec]
+impl i32 {
+    #[pure]
+    #[ensures(result as i64 == if self as i64 + rhs as i64 > i32::MAX as i64 {
+        self as i64 + rhs as i64 - (1 << 32)
+    } else if self as i64 + rhs as i64 < i32::MIN as i64 {
+        self as i64 + rhs as i64 + (1 << 32)
+    } else {
+        self as i64 + rhs as i64
+    })]
+    pub const fn wrapping_add(self, rhs: i32) -> i32;
+}
+
+// Powers of two are stated with `binary::pow2`
+#[extern_spec]
+impl u32 {
+    #[pure]
+    #[ensures(result == exists(|k: u32| k < 32 && self as u64 == crate::binary::pow2(k)))]
+    pub const fn is_power_of_two(self) -> bool;
+
+    // The next power of two has to fit, `2^31` is the largest one
+    #[requires(self <= 1 << 31)]
+    #[ensures(result.is_power_of_two())]
+    #[ensures(self <= result)]
+    #[ensures(self <= 1 ==> result == 1)]
+    #[ensures(self > 1 ==> result / 2 < self)]
+    pub const fn next_power_of_two(self) -> u32;
+}
+
+// Comparisons on generic types are only usable in specs if they are pure.
+// The impls for the primitive integers below then tell Prusti what they compute.
+#[extern_spec(std::cmp)]
+trait PartialEq<Rhs: ?Sized = Self> {
+    #[pure]
+    fn eq(&self, other: &Rhs) -> bool;
+
+    #[pure]
+    fn ne(&self, other: &Rhs) -> bool;
+}
+
+#[extern_spec(std::cmp)]
+trait PartialOrd<Rhs: ?Sized = Self> {
+    #[pure]
+    fn lt(&self, other: &Rhs) -> bool;
+
+    #[pure]
+    fn le(&self, other: &Rhs) -> bool;
+
+    #[pure]
+    fn gt(&self, other: &Rhs) -> bool;
+
+    #[pure]
+    fn ge(&self, other: &Rhs) -> bool;
+}
+
+#[extern_spec(std::cmp)]
+trait Ord {
+    #[pure]
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering;
+}
+
+#[extern_spec]
+impl PartialEq for i32 {
+    #[pure]
+    #[ensures(result == (*self == *other))]
+    fn eq(&self, other: &i32) -> bool;
+}
+
+#[extern_spec]
+impl PartialOrd for i32 {
+    #[pure]
+    #[ensures(result == (*self < *other))]
+    fn lt(&self, other: &i32) -> bool;
+
+    #[pure]
+    #[ensures(result == (*self <= *other))]
+    fn le(&self, other: &i32) -> bool;
+
+    #[pure]
+    #[ensures(result == (*self > *other))]
+    fn gt(&self, other: &i32) -> bool;
+
+    #[pure]
+    #[ensures(result == (*self >= *other))]
+    fn ge(&self, other: &i32) -> bool;
+}
+
+#[extern_spec]
+impl Ord for i32 {
+    #[pure]
+    #[ensures((*self < *other) == matches!(result, std::cmp::Ordering::Less))]
+    #[ensures((*self == *other) == matches!(result, std::cmp::Ordering::Equal))]
+    #[ensures((*self > *other) == matches!(result, std::cmp::Ordering::Greater))]
+    fn cmp(&self, other: &i32) -> std::cmp::Ordering;
+}
+
+#[extern_spec]
+impl PartialEq for u64 {
+    #[pure]
+    #[ensures(result == (*self == *other))]
+    fn eq(&self, other: &u64) -> bool;
+}
+
+#[extern_spec]
+impl PartialOrd for u64 {
+    #[pure]
+    #[ensures(result == (*self < *other))]
+    fn lt(&self, other: &u64) -> bool;
+
+    #[pure]
+    #[ensures(result == (*self <= *other))]
+    fn le(&self, other: &u64) -> bool;
+
+    #[pure]
+    #[ensures(result == (*self > *other))]
+    fn gt(&self, other: &u64) -> bool;
+
+    #[pure]
+    #[ensures(result == (*self >= *other))]
+    fn ge(&self, other: &u64) -> bool;
+}
+
+#[extern_spec]
+impl Ord for u64 {
+    #[pure]
+    #[ensures((*self < *other) == matches!(result, std::cmp::Ordering::Less))]
+    #[ensures((*self == *other) == matches!(result, std::cmp::Ordering::Equal))]
+    #[ensures((*self > *other) == matches!(result, std::cmp::Ordering::Greater))]
+    fn cmp(&self, other: &u64) -> std::cmp::Ordering;
+}
@@ -0,0 +1,73 @@
+use prusti_contracts::*;
+
+// Builds a string piece by piece while keeping track of its length.
+// The bytes themselves are left to `String` (trusted through its extern specs),
+// the verified part is the length arithmetic.
+pub struct StringBuilder {
+    buf: String,
+}
+
+impl StringBuilder {
+    #[ensures(result.len() == 0)]
+    pub fn new() -> Self {
+        StringBuilder { buf: String::new() }
+    }
+
+    // Length in bytes
+    #[pure]
+    pub fn len(&self) -> usize {
+        self.buf.len()
+    }
+
+    #[pure]
+    #[ensures(result == (self.len() == 0))]
+    pub fn is_empty(&self) -> bool {
+        self.buf.is_empty()
+    }
+
+    #[ensures(self.len() == old(self.len()) + s.len())]
+    pub fn append(&mut self, s: &str) {
+        self.buf.push_str(s);
+    }
+
+    #[ensures(self.len() == old(self.len()) + s.len() + 1)]
+    pub fn append_line(&mut self, s: &str) {
+        self.append(s);
+        self.append("\n");
+    }
+
+    #[ensures(self.len() == 0)]
+    pub fn clear(&mut self) {
+        self.buf.clear();
+    }
+
+    #[pure]
+    #[ensures(result.len() == self.len())]
+    pub fn as_str(&self) -> &str {
+        self.buf.as_str()
+    }
+
+    #[ensures(result.len() == self.len())]
+    pub fn build(self) -> String {
+        self.buf
+    }
+}
+
+#[cfg(prusti)]
+mod prusti_tests {
+    use super::*;
+
+    // Prusti doesn't know the length of string literals, so the test works with parameters
+    fn _test_string_builder(a: &str, b: &str) {
+        let mut builder = StringBuilder::new();
+        builder.append(a);
+        builder.append_line(b);
+        prusti_assert!(builder.len() == a.len() + b.len() + 1);
+
+        builder.clear();
+        prusti_assert!(builder.is_empty());
+        builder.append(a);
+        let s = builder.build();
+        prusti_assert!(s.len() == a.len());
+    }
+}
\ No newline at end of file
@@ -0,0 +1,289 @@
+use prusti_contracts::*;
+
+// Algorithms on byte strings. Every index is checked by the verifier, and the specs
+// state the property itself (with quantifiers) rather than the algorithm.
+
+// Compares the first half with the mirrored second half. The middle byte of an odd-length
+// string is its own mirror image, and the second half is covered by symmetry.
+#[ensures(result == forall(|i: usize| i < s.len() ==> s[i] == s[s.len() - 1 - i]))]
+pub fn is_palindrome(s: &[u8]) -> bool {
+    let n = s.len();
+    let mut i = 0;
+    while i < n / 2 {
+        body_invariant!(i < n / 2);
+        body_invariant!(forall(|k: usize| k < i ==> s[k] == s[n - 1 - k]));
+
+        if s[i] != s[n - 1 - i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+// Number of occurrences of `byte` in `s[..n]`
+#[pure]
+#[requires(n <= s.len())]
+#[cfg_attr(prusti, terminates(Int::new_usize(n)))]
+pub fn count_byte(s: &[u8], byte: u8, n: usize) -> usize {
+    if n == 0 {
+        0
+    } else if s[n - 1] == byte {
+        count_byte(s, byte, n - 1) + 1
+    } else {
+        count_byte(s, byte, n - 1)
+    }
+}
+
+// One counter per possible byte value
+#[ensures(forall(|c: u8| result[c as usize] == count_byte(s, c, s.len())))]
+fn byte_counts(s: &[u8]) -> [usize; 256] {
+    let mut counts = [0; 256];
+    let mut i = 0;
+    while i < s.len() {
+        body_invariant!(i < s.len());
+        body_invariant!(forall(|c: u8| counts[c as usize] == count_byte(s, c, i)));
+
+        counts[s[i] as usize] += 1;
+        i += 1;
+    }
+    counts
+}
+
+// Two strings are anagrams if every byte occurs equally often in both
+#[ensures(result == forall(|c: u8| count_byte(a, c, a.len()) == count_byte(b, c, b.len())))]
+pub fn is_anagram(a: &[u8], b: &[u8]) -> bool {
+    let counts_a = byte_counts(a);
+    let counts_b = byte_counts(b);
+    let mut i = 0;
+    while i < 256 {
+        body_invariant!(i < 256);
+        body_invariant!(forall(|c: u8| (c as usize) < i ==> counts_a[c as usize] == counts_b[c as usize]));
+
+        if counts_a[i] != counts_b[i] {
+            // The byte `i` is the witness that the quantifier in the postcondition is false
+            prusti_assert!(count_byte(a, i as u8, a.len()) != count_byte(b, i as u8, b.len()));
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+#[ensures(result == forall(|i: usize| i < s.len() ==> s[i] < 128))]
+pub fn is_ascii(s: &[u8]) -> bool {
+    let mut i = 0;
+    while i < s.len() {
+        body_invariant!(i < s.len());
+        body_invariant!(forall(|k: usize| k < i ==> s[k] < 128));
+
+        if s[i] >= 128 {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+// Lowercase letters are 32 above their uppercase version, every other byte stays the same
+#[ensures(s.len() == old(s.len()))]
+#[ensures(forall(|i: usize| (i < s.len() && b'a' <= old(s[i]) && old(s[i]) <= b'z') ==>
+    s[i] == old(s[i]) - 32))]
+#[ensures(forall(|i: usize| (i < s.len() && !(b'a' <= old(s[i]) && old(s[i]) <= b'z')) ==>
+    s[i] == old(s[i])))]
+pub fn to_ascii_uppercase_in_place(s: &mut [u8]) {
+    let mut i = 0;
+    while i < s.len() {
+        body_invariant!(i < s.len() && s.len() == old(s.len()));
+        body_invariant!(forall(|k: usize| (k < i && b'a' <= old(s[k]) && old(s[k]) <= b'z') ==>
+            s[k] == old(s[k]) - 32));
+        body_invariant!(forall(|k: usize| (k < i && !(b'a' <= old(s[k]) && old(s[k]) <= b'z')) ==>
+            s[k] == old(s[k])));
+        body_invariant!(forall(|k: usize| (i <= k && k < s.len()) ==> s[k] == old(s[k])));
+
+        if b'a' <= s[i] && s[i] <= b'z' {
+            s[i] -= 32;
+        }
+        i += 1;
+    }
+}
+
+predicate! {
+    // `needle` occurs in `hay` starting at position `i`
+    pub fn matches_at(hay: &[u8], needle: &[u8], i: usize) -> bool {
+        i + needle.len() <= hay.len()
+        && forall(|j: usize| j < needle.len() ==> hay[i + j] == needle[j])
+    }
+}
+
+// Returns the first position where `needle` occurs.
+// Tries every start position and compares byte by byte until the first mismatch.
+#[ensures(match result {
+    Some(i) => matches_at(hay, needle, i)
+        && forall(|k: usize| k < i ==> !matches_at(hay, needle, k)),
+    None => forall(|k: usize| !matches_at(hay, needle, k)),
+})]
+pub fn find_substring(hay: &[u8], needle: &[u8]) -> Option<usize> {
+    let mut i = 0;
+    while i + needle.len() <= hay.len() {
+        body_invariant!(i + needle.len() <= hay.len());
+        body_invariant!(forall(|k: usize| k < i ==> !matches_at(hay, needle, k)));
+
+        let mut j = 0;
+        while j < needle.len() && hay[i + j] == needle[j] {
+            body_invariant!(j < needle.len() && i + needle.len() <= hay.len());
+            body_invariant!(forall(|m: usize| m <= j ==> hay[i + m] == needle[m]));
+
+            j += 1;
+        }
+        if j == needle.len() {
+            return Some(i);
+        }
+        i += 1;
+    }
+    None
+}
+
+#[pure]
+#[ensures(result <= x && result <= y && result <= z)]
+fn min3(x: usize, y: usize, z: usize) -> usize {
+    if x <= y && x <= z {
+        x
+    } else if y <= z {
+        y
+    } else {
+        z
+    }
+}
+
+#[pure]
+fn max(x: usize, y: usize) -> usize {
+    if x >= y {
+        x
+    } else {
+        y
+    }
+}
+
+// Levenshtein distance between `a[..i]` and `b[..j]`: the fewest insertions, deletions and
+// substitutions that turn one into the other. Exponential, only meant for specs.
+#[pure]
+#[requires(i <= a.len() && j <= b.len())]
+#[cfg_attr(prusti, terminates(Int::new_usize(i) + Int::new_usize(j)))]
+pub fn levenshtein(a: &[u8], b: &[u8], i: usize, j: usize) -> usize {
+    if i == 0 {
+        j
+    } else if j == 0 {
+        i
+    } else {
+        let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+        min3(
+            levenshtein(a, b, i - 1, j) + 1,
+            levenshtein(a, b, i, j - 1) + 1,
+            levenshtein(a, b, i - 1, j - 1) + cost,
+        )
+    }
+}
+
+// The usual dynamic program, keeping one row of the table at a time. Row `i` holds the
+// distances from `a[..i]` to every prefix of `b`. Every entry is bounded by the longer of the
+// two prefixes: the diagonal step costs at most 1, so entry `(i, j)` is at most
+// `max(i - 1, j - 1) + 1`.
+#[ensures(result <= max(a.len(), b.len()))]
+pub fn edit_distance_bounded(a: &[u8], b: &[u8]) -> usize {
+    // Row 0: turning the empty string into `b[..j]` takes `j` insertions
+    let mut prev = Vec::new();
+    let mut j = 0;
+    while j <= b.len() {
+        body_invariant!(j <= b.len() && prev.len() == j);
+        body_invariant!(forall(|k: usize| k < j ==> prev[k] <= k));
+
+        prev.push(j);
+        j += 1;
+    }
+
+    let mut i = 1;
+    while i <= a.len() {
+        body_invariant!(1 <= i && i <= a.len());
+        body_invariant!(prev.len() == b.len() + 1);
+        body_invariant!(forall(|k: usize| k < prev.len() ==> prev[k] <= max(i - 1, k)));
+
+        // Column 0: turning `a[..i]` into the empty string takes `i` deletions
+        let mut cur = Vec::new();
+        cur.push(i);
+        let mut j = 1;
+        while j <= b.len() {
+            body_invariant!(1 <= j && j <= b.len() && cur.len() == j);
+            body_invariant!(prev.len() == b.len() + 1);
+            body_invariant!(forall(|k: usize| k < prev.len() ==> prev[k] <= max(i - 1, k)));
+            body_invariant!(forall(|k: usize| k < j ==> cur[k] <= max(i, k)));
+
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            cur.push(min3(prev[j] + 1, cur[j - 1] + 1, prev[j - 1] + cost));
+            j += 1;
+        }
+        prev = cur;
+        i += 1;
+    }
+    prev[b.len()]
+}
+
+// That the table really computes `levenshtein` is not verified: it needs an invariant relating
+// every entry of the row to the recursive definition, which is left as a trusted lemma.
+#[trusted]
+#[ensures(result == levenshtein(a, b, a.len(), b.len()))]
+#[ensures(result <= max(a.len(), b.len()))]
+pub fn edit_distance(a: &[u8], b: &[u8]) -> usize {
+    edit_distance_bounded(a, b)
+}
+
+#[cfg(prusti)]
+mod prusti_tests {
+    use super::*;
+
+    fn _test_palindrome() {
+        let yes = is_palindrome(&[1, 2, 3, 2, 1]);
+        prusti_assert!(yes);
+        let no = is_palindrome(&[1, 2]);
+        prusti_assert!(!no);
+        let empty = is_palindrome(&[]);
+        prusti_assert!(empty);
+    }
+
+    fn _test_anagram(s: &[u8]) {
+        let same = is_anagram(s, s);
+        prusti_assert!(same);
+        let swapped = is_anagram(&[1, 2], &[2, 1]);
+        prusti_assert!(swapped);
+    }
+
+    fn _test_ascii() {
+        let plain = is_ascii(&[72, 105]);
+        prusti_assert!(plain);
+        let high = is_ascii(&[72, 200]);
+        prusti_assert!(!high);
+
+        let mut s = [b'a', b'Z', b'1'];
+        to_ascii_uppercase_in_place(&mut s);
+        prusti_assert!(s[0] == b'A' && s[1] == b'Z' && s[2] == b'1');
+    }
+
+    fn _test_find_substring(hay: &[u8]) {
+        let found = find_substring(&[1, 2, 3, 2, 3], &[2, 3]);
+        prusti_assert!(found === Some(1));
+        let missing = find_substring(&[1, 2], &[2, 1]);
+        prusti_assert!(missing.is_none());
+        // The empty needle occurs everywhere, so it is found at the start
+        let empty = find_substring(hay, &[]);
+        prusti_assert!(empty === Some(0));
+    }
+
+    fn _test_edit_distance(a: &[u8]) {
+        let d = edit_distance_bounded(&[1, 2, 3], &[1, 3]);
+        prusti_assert!(d <= 3);
+        let to_empty = edit_distance_bounded(a, &[]);
+        prusti_assert!(to_empty <= a.len());
+        let exact = edit_distance(&[1, 2], &[1, 2]);
+        prusti_assert!(exact == levenshtein(&[1, 2], &[1, 2], 2, 2));
+    }
+}
\ No newline at end of file
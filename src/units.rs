@@ -0,0 +1,69 @@
+use prusti_contracts::*;
+use std::marker::PhantomData;
+
+// Quantities tagged with their unit. The unit only exists in the type, so mixing
+// meters and seconds is a compile error, while the verifier takes care of the values.
+
+pub struct Meters;
+pub struct Seconds;
+// Unit of `A / B`, e.g. `Per<Meters, Seconds>` for speeds
+pub struct Per<A, B>(PhantomData<(A, B)>);
+
+pub struct Quantity<U> {
+    value: i64,
+    unit: PhantomData<U>,
+}
+
+impl<U> Quantity<U> {
+    #[ensures(result.value() == value)]
+    pub fn new(value: i64) -> Self {
+        Quantity { value, unit: PhantomData }
+    }
+
+    #[pure]
+    pub fn value(&self) -> i64 {
+        self.value
+    }
+
+    // Only quantities of the same unit can be added
+    #[requires(i64::MIN as i128 <= self.value() as i128 + other.value() as i128)]
+    #[requires(self.value() as i128 + other.value() as i128 <= i64::MAX as i128)]
+    #[ensures(result.value() == self.value() + other.value())]
+    pub fn add(&self, other: &Quantity<U>) -> Quantity<U> {
+        Quantity::new(self.value + other.value)
+    }
+
+    #[requires(i64::MIN as i128 <= self.value() as i128 * factor as i128)]
+    #[requires(self.value() as i128 * factor as i128 <= i64::MAX as i128)]
+    #[ensures(result.value() == self.value() * factor)]
+    pub fn scale(&self, factor: i64) -> Quantity<U> {
+        Quantity::new(self.value * factor)
+    }
+
+    // Dividing two quantities divides their units (rounding towards zero)
+    #[requires(other.value() != 0)]
+    #[requires(!(self.value() == i64::MIN && other.value() == -1))]
+    #[ensures(result.value() == self.value() / other.value())]
+    pub fn ratio<V>(&self, other: &Quantity<V>) -> Quantity<Per<U, V>> {
+        Quantity::new(self.value / other.value)
+    }
+}
+
+#[cfg(prusti)]
+mod prusti_tests {
+    use super::*;
+
+    fn _test_units() {
+        let distance: Quantity<Meters> = Quantity::new(100);
+        let more: Quantity<Meters> = Quantity::new(50);
+        let time: Quantity<Seconds> = Quantity::new(30);
+
+        let total = distance.add(&more);
+        prusti_assert!(total.value() == 150);
+        // let wrong = distance.add(&time); // doesn't compile: meters plus seconds
+
+        let speed: Quantity<Per<Meters, Seconds>> = total.ratio(&time);
+        prusti_assert!(speed.value() == 5);
+        // let stopped = total.ratio(&Quantity::<Seconds>::new(0)); // FAILS: division by zero
+    }
+}
\ No newline at end of file
@@ -0,0 +1,130 @@
+use prusti_contracts::*;
+
+use crate::sorted_list::SortedList;
+
+// A set of integers, stored as a sorted list without duplicates.
+//
+// Clients reason about it through ghost models instead of the list:
+// - `model()` is the set of elements. `prusti-contracts` 0.1.8 has no ghost `Set`,
+//   so it is a `Map` to `()`, where `contains` is set membership.
+// - `elements()` is the sequence of elements in ascending order.
+// The models only exist for the verifier, hence `cfg(prusti)`.
+#[invariant(forall(|i: usize, j: usize| (i < j && j < self.elems.len()) ==>
+    *self.elems.lookup(i) < *self.elems.lookup(j)))]
+pub struct VerifiedSet {
+    elems: SortedList<i32>,
+}
+
+impl VerifiedSet {
+    #[ensures(result.len() == 0)]
+    pub fn new() -> Self {
+        VerifiedSet { elems: SortedList::new() }
+    }
+
+    #[pure]
+    pub fn len(&self) -> usize {
+        self.elems.len()
+    }
+
+    #[pure]
+    #[requires(index < self.len())]
+    pub fn lookup(&self, index: usize) -> i32 {
+        *self.elems.lookup(index)
+    }
+
+    // Set of the elements from index `k` onwards
+    #[cfg(prusti)]
+    #[pure]
+    #[requires(k <= self.len())]
+    #[terminates(Int::new_usize(self.len() - k))]
+    fn model_from(&self, k: usize) -> Map<i32, ()> {
+        if k == self.len() {
+            Map::empty()
+        } else {
+            self.model_from(k + 1).insert(self.lookup(k), ())
+        }
+    }
+
+    #[cfg(prusti)]
+    #[pure]
+    pub fn model(&self) -> Map<i32, ()> {
+        self.model_from(0)
+    }
+
+    // Sequence of the elements from index `k` onwards
+    #[cfg(prusti)]
+    #[pure]
+    #[requires(k <= self.len())]
+    #[terminates(Int::new_usize(self.len() - k))]
+    fn elements_from(&self, k: usize) -> Seq<i32> {
+        if k == self.len() {
+            Seq::empty()
+        } else {
+            Seq::single(self.lookup(k)).concat(self.elements_from(k + 1))
+        }
+    }
+
+    #[cfg(prusti)]
+    #[pure]
+    pub fn elements(&self) -> Seq<i32> {
+        self.elements_from(0)
+    }
+
+    // Lemma: `x` is in the model of the elements from `k` onwards iff it is one of them
+    #[cfg(prusti)]
+    #[pure]
+    #[requires(k <= self.len())]
+    #[ensures(self.model_from(k).contains(x) ==
+        exists(|i: usize| k <= i && i < self.len() && self.lookup(i) == x))]
+    #[terminates(Int::new_usize(self.len() - k))]
+    fn lemma_model_from(&self, k: usize, x: i32) -> bool {
+        k == self.len() || self.lemma_model_from(k + 1, x)
+    }
+
+    #[ensures(self.lemma_model_from(0, x))]
+    #[ensures(result == self.model().contains(x))]
+    pub fn contains(&self, x: i32) -> bool {
+        self.elems.contains(&x)
+    }
+
+    // Adding an element that is already there changes nothing
+    #[ensures(forall(|y: i32| old(self.lemma_model_from(0, y)) && self.lemma_model_from(0, y)))]
+    #[ensures(self.model().contains(x))]
+    #[ensures(forall(|y: i32| y != x ==>
+        self.model().contains(y) == old(self.model()).contains(y)))]
+    #[ensures(old(self.model().contains(x)) ==> self.len() == old(self.len()))]
+    #[ensures(!old(self.model().contains(x)) ==> self.len() == old(self.len()) + 1)]
+    pub fn insert(&mut self, x: i32) {
+        if !self.elems.contains(&x) {
+            self.elems.insert(x);
+        }
+    }
+
+    // The smallest element comes first in the ascending sequence
+    #[pure]
+    #[requires(self.len() > 0)]
+    #[ensures(result == self.elements()[0])]
+    #[ensures(forall(|i: usize| i < self.len() ==> result <= self.lookup(i)))]
+    pub fn min(&self) -> i32 {
+        self.lookup(0)
+    }
+}
+
+#[cfg(prusti)]
+mod prusti_tests {
+    use super::*;
+
+    fn _test_set() {
+        let mut set = VerifiedSet::new();
+        set.insert(3);
+        set.insert(1);
+        set.insert(3);
+        prusti_assert!(set.len() == 2);
+        prusti_assert!(set.model().contains(1) && set.model().contains(3));
+        prusti_assert!(!set.model().contains(2));
+
+        let found = set.contains(1);
+        prusti_assert!(found);
+        prusti_assert!(set.min() == 1);
+    }
+}
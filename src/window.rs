@@ -0,0 +1,62 @@
+use prusti_contracts::*;
+
+// Maximum of every window of `w` consecutive elements.
+// The straightforward version with a nested loop: the monotonic-deque algorithm is faster,
+// but its invariant is about the deque instead of the windows.
+
+predicate! {
+    // `m` is the largest element of `s[start..start + w]`
+    pub fn is_window_max(s: &[i32], start: usize, w: usize, m: i32) -> bool {
+        forall(|j: usize| (start <= j && j < start + w) ==> s[j] <= m)
+        && exists(|j: usize| start <= j && j < start + w && s[j] == m)
+    }
+}
+
+#[requires(1 <= w && start + w <= s.len())]
+#[ensures(is_window_max(s, start, w, result))]
+fn max_of(s: &[i32], start: usize, w: usize) -> i32 {
+    let mut max = s[start];
+    let mut j = start + 1;
+    while j < start + w {
+        body_invariant!(start < j && j < start + w);
+        body_invariant!(forall(|k: usize| (start <= k && k < j) ==> s[k] <= max));
+        body_invariant!(exists(|k: usize| start <= k && k < j && s[k] == max));
+
+        if s[j] > max {
+            max = s[j];
+        }
+        j += 1;
+    }
+    max
+}
+
+// There are `s.len() - w + 1` windows, the first one starting at 0
+#[requires(1 <= w && w <= s.len())]
+#[ensures(result.len() == s.len() - w + 1)]
+#[ensures(forall(|i: usize| i < result.len() ==> is_window_max(s, i, w, result[i])))]
+pub fn window_max(s: &[i32], w: usize) -> Vec<i32> {
+    let mut result = Vec::new();
+    let mut start = 0;
+    while start + w <= s.len() {
+        body_invariant!(start + w <= s.len());
+        body_invariant!(result.len() == start);
+        body_invariant!(forall(|i: usize| i < start ==> is_window_max(s, i, w, result[i])));
+
+        result.push(max_of(s, start, w));
+        start += 1;
+    }
+    result
+}
+
+#[cfg(prusti)]
+mod prusti_tests {
+    use super::*;
+
+    fn _test_window_max() {
+        let s = [1, 3, 2, 5, 4];
+        let maxima = window_max(&s, 3);
+        prusti_assert!(maxima.len() == 3);
+        prusti_assert!(maxima[0] == 3 && maxima[1] == 5 && maxima[2] == 5);
+        // window_max(&s, 6); // FAILS: the window is longer than the slice
+    }
+}
\ No newline at end of file
@@ -0,0 +1,129 @@
+use prusti_contracts::*;
+
+use crate::list_generic_with_peek::List;
+// Lemmas are only called from specs
+#[cfg(prusti)]
+use crate::lemmas::lemma_lookup_push;
+
+// A list with a cursor: the focused element, the elements before it and the ones after it.
+// Moving the cursor by one and changing the focused element take constant time.
+//
+// `left` is stored in reverse (its head is the element right before the focus), so both
+// neighbours of the focus are list heads. Clients see the whole sequence through `len` and
+// `get`, with the focus at position `pos`. Moving changes `pos` but never the sequence,
+// and `modify_focus` changes exactly one position of it.
+pub struct Zipper<T> {
+    left: List<T>,
+    focus: T,
+    right: List<T>,
+}
+
+impl<T> Zipper<T> {
+    // Focuses the first element
+    #[requires(!list.is_empty())]
+    #[ensures(result.pos() == 0)]
+    #[ensures(result.len() == list.len())]
+    #[ensures(forall(|i: usize| i < list.len() ==> result.get(i) === list.lookup(i)))]
+    pub fn from_list(mut list: List<T>) -> Self {
+        let focus = list.pop();
+        Zipper { left: List::new(), focus, right: list }
+    }
+
+    #[pure]
+    pub fn len(&self) -> usize {
+        self.left.len() + 1 + self.right.len()
+    }
+
+    #[pure]
+    #[ensures(result < self.len())]
+    pub fn pos(&self) -> usize {
+        self.left.len()
+    }
+
+    #[pure]
+    #[requires(i < self.len())]
+    pub fn get(&self, i: usize) -> &T {
+        if i < self.left.len() {
+            self.left.lookup_from_back(i)
+        } else if i == self.left.len() {
+            &self.focus
+        } else {
+            self.right.lookup(i - self.left.len() - 1)
+        }
+    }
+
+    predicate! {
+        // two-state predicate: the same sequence, wherever the focus is
+        pub fn same_elements(&self, prev: &Self) -> bool {
+            self.len() == prev.len()
+            && forall(|i: usize| i < self.len() ==> self.get(i) === prev.get(i))
+        }
+    }
+
+    #[requires(self.pos() > 0)]
+    #[ensures(self.pos() == old(self.pos()) - 1)]
+    #[ensures(self.same_elements(&old(snap(self))))]
+    pub fn move_left(&mut self) {
+        let prev = self.left.pop();
+        let old_focus = std::mem::replace(&mut self.focus, prev);
+        self.right.push(old_focus);
+    }
+
+    #[requires(self.pos() + 1 < self.len())]
+    #[ensures(self.pos() == old(self.pos()) + 1)]
+    #[ensures(self.same_elements(&old(snap(self))))]
+    pub fn move_right(&mut self) {
+        let next = self.right.pop();
+        let old_focus = std::mem::replace(&mut self.focus, next);
+        #[cfg(prusti)]
+        let left = ghost!(snap(&self.left));
+        self.left.push(old_focus);
+        // Counted from the back, the elements before the old focus didn't move
+        prusti_assert!(forall(|k: usize| k < left.len() ==> lemma_lookup_push(&self.left, &left, k)));
+    }
+
+    #[ensures(self.pos() == old(self.pos()) && self.len() == old(self.len()))]
+    #[ensures(snap(self.get(self.pos())) === value)]
+    #[ensures(forall(|i: usize| (i < self.len() && i != self.pos()) ==> self.get(i) === old(self.get(i))))]
+    pub fn modify_focus(&mut self, value: T) {
+        self.focus = value;
+    }
+
+    // Moves the focus to the front and puts it back on top of the rest
+    #[ensures(result.len() == old(self.len()))]
+    #[ensures(forall(|i: usize| i < result.len() ==> result.lookup(i) === old(self.get(i))))]
+    pub fn into_list(mut self) -> List<T> {
+        while self.pos() > 0 {
+            body_invariant!(self.pos() > 0);
+            body_invariant!(self.same_elements(&old(snap(&self))));
+
+            self.move_left();
+        }
+        let Zipper { focus, mut right, .. } = self;
+        right.push(focus);
+        right
+    }
+}
+
+#[cfg(prusti)]
+mod prusti_tests {
+    use super::*;
+
+    fn _test_zipper() {
+        let mut list = List::new();
+        list.push(3);
+        list.push(2);
+        list.push(1);
+        let mut zipper = Zipper::from_list(list);
+        zipper.move_right();
+        prusti_assert!(zipper.pos() == 1 && *zipper.get(1) == 2);
+        zipper.modify_focus(5);
+        zipper.move_right();
+        // zipper.move_right(); // FAILS: the focus is already on the last element
+        zipper.move_left();
+        prusti_assert!(*zipper.get(0) == 1 && *zipper.get(1) == 5 && *zipper.get(2) == 3);
+
+        let back = zipper.into_list();
+        prusti_assert!(back.len() == 3 && *back.lookup(1) == 5);
+    }
+}
\ No newline at end of file